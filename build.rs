@@ -0,0 +1,40 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Generates a table of every data/{day}.txt present at build time, so
+// those inputs can be embedded in the binary with include_str!.
+fn main() {
+    println!("cargo:rerun-if-changed=data");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let data_dir = Path::new(&manifest_dir).join("data");
+
+    let mut days: Vec<u8> = vec![];
+    if let Ok(entries) = fs::read_dir(&data_dir) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if let Some(day) = name.strip_suffix(".txt") {
+                if let Ok(day) = day.parse::<u8>() {
+                    days.push(day);
+                }
+            }
+        }
+    }
+
+    days.sort_unstable();
+
+    let mut table = String::from("pub(crate) const EMBEDDED: &[(u8, &str)] = &[\n");
+    for day in days {
+        let path = data_dir.join(format!("{}.txt", day));
+        table.push_str(&format!(
+            "    ({}, include_str!({:?})),\n",
+            day,
+            path.display().to_string()
+        ));
+    }
+    table.push_str("];\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("embedded.rs");
+    fs::write(out, table).unwrap();
+}
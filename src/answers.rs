@@ -0,0 +1,193 @@
+use crate::{data_dir, examples, Error, ParseError, Part, Puzzle};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// The answers file, kept beside the inputs it has answers for.
+pub fn answers_path() -> PathBuf {
+    data_dir().join("answers.tsv")
+}
+
+// Identifies one recorded answer: which day and part, for which input.
+// The input is named the way Source::name names it, e.g. "input".
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct Key {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+}
+
+impl Key {
+    pub fn new(day: u8, part: Part, input: &str) -> Key {
+        Key {
+            day,
+            part,
+            input: input.to_string(),
+        }
+    }
+}
+
+// Answers confirmed correct, to check solutions against after changes.
+// Stored as tab-separated day, part, input and answer, one per line.
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<Key, String>);
+
+impl Answers {
+    // Loads answers from path. A missing file is just no answers yet.
+    pub fn load(path: &Path) -> Result<Answers, Error> {
+        let text = match fs::read_to_string(path) {
+            Ok(t) => t,
+            Err(why) if why.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(why) => return Err(why.into()),
+        };
+
+        Answers::parse(&text).map_err(|e| e.with_path(path))
+    }
+
+    fn parse(text: &str) -> Result<Answers, Error> {
+        let mut answers = Answers::default();
+
+        for (i, line) in text.lines().enumerate() {
+            let n = i + 1;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() != 4 {
+                return Err(ParseError::new(n, 1, "expected day, part, input and answer").into());
+            }
+
+            let day = fields[0]
+                .parse::<u8>()
+                .map_err(|_| ParseError::at(n, line, fields[0], "invalid day"))?;
+            let part = match fields[1] {
+                "1" => Part::One,
+                "2" => Part::Two,
+                p => return Err(ParseError::at(n, line, p, "expected part 1 or 2").into()),
+            };
+
+            answers.record(day, part, fields[2], fields[3]);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut text = String::from("# day\tpart\tinput\tanswer\n");
+        for (key, answer) in &self.0 {
+            writeln!(text, "{}\t{}\t{}\t{}", key.day, key.part, key.input, answer).unwrap();
+        }
+
+        fs::write(path, text)
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.0.get(&Key::new(day, part, input)).map(|a| a.as_str())
+    }
+
+    // Records answer, replacing any answer already recorded for the same
+    // day, part and input. Returns the replaced answer.
+    pub fn record(&mut self, day: u8, part: Part, input: &str, answer: &str) -> Option<String> {
+        self.0
+            .insert(Key::new(day, part, input), answer.to_string())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &str)> {
+        self.0.iter().map(|(k, a)| (k, a.as_str()))
+    }
+}
+
+// Solves each of day's example inputs with puzzle and checks every part
+// against the answer recorded for that example. Returns what went wrong,
+// if anything, one line per problem.
+pub fn check_examples(day: u8, puzzle: &dyn Puzzle) -> Vec<String> {
+    let answers = match Answers::load(&answers_path()) {
+        Ok(a) => a,
+        Err(why) => return vec![why.to_string()],
+    };
+
+    let mut failures = vec![];
+    for source in examples(day) {
+        let name = source.name();
+        let parsed = source
+            .read(day)
+            .map_err(Error::from)
+            .and_then(|input| puzzle.prepare(&input));
+        let parsed = match parsed {
+            Ok(p) => p,
+            Err(why) => {
+                failures.push(format!("day {} {}: {}", day, name, why));
+                continue;
+            }
+        };
+
+        for part in [Part::One, Part::Two].iter() {
+            let expected = answers.get(day, *part, &name);
+            match (parsed.solve(*part), expected) {
+                (Err(Error::NotImplemented), _) => (),
+                (Ok(actual), Some(expected)) if actual == expected => (),
+                (Ok(actual), Some(expected)) => failures.push(format!(
+                    "day {} part {} {}: expected {}, got {}",
+                    day, part, name, expected, actual
+                )),
+                (Ok(_), None) => failures.push(format!(
+                    "day {} part {} {}: no answer recorded",
+                    day, part, name
+                )),
+                (Err(why), _) => {
+                    failures.push(format!("day {} part {} {}: {}", day, part, name, why))
+                }
+            }
+        }
+    }
+
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_works() {
+        let answers = Answers::parse("# header\n1\t1\tinput\t1557\n1\t2\texample\t5\n").unwrap();
+        assert_eq!(answers.get(1, Part::One, "input"), Some("1557"));
+        assert_eq!(answers.get(1, Part::Two, "example"), Some("5"));
+        assert_eq!(answers.get(1, Part::Two, "input"), None);
+    }
+
+    #[test]
+    fn parse_fails_with_location() {
+        let err = Answers::parse("1\t1\tinput\t1557\n1\t3\tinput\t5\n").unwrap_err();
+        match err {
+            Error::Parse(e) => assert_eq!((e.line, e.column), (2, 3)),
+            e => panic!("expected parse error, got {}", e),
+        }
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.tsv", std::process::id()));
+
+        let mut answers = Answers::default();
+        answers.record(8, Part::Two, "input", "989396");
+        answers.record(1, Part::One, "input", "1557");
+        assert_eq!(
+            answers.record(1, Part::One, "input", "1558"),
+            Some("1557".to_string())
+        );
+        answers.save(&path).unwrap();
+
+        let loaded = Answers::load(&path).unwrap();
+        let all: Vec<(&Key, &str)> = loaded.iter().collect();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].0, &Key::new(1, Part::One, "input"));
+        assert_eq!(all[0].1, "1558");
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(Answers::load(&path).unwrap().iter().count(), 0);
+    }
+}
@@ -0,0 +1,385 @@
+use crate::{state_dir, Error, Input, ParseError, Part, Puzzle};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// Summary of how long many runs of the same thing took.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        if samples.is_empty() {
+            return Stats {
+                runs: 0,
+                min: Duration::ZERO,
+                median: Duration::ZERO,
+                mean: Duration::ZERO,
+                stddev: Duration::ZERO,
+            };
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let len = sorted.len();
+        let median = if len % 2 == 0 {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(|d| d.as_secs_f64()).collect();
+        let mean = secs.iter().sum::<f64>() / len as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / len as f64;
+
+        Stats {
+            runs: len,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    fn to_json(self) -> String {
+        format!(
+            "{{\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+            self.runs,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos(),
+            self.stddev.as_nanos()
+        )
+    }
+}
+
+// Runs f the given number of times after one untimed warm up run, timing
+// each. Stops early with the error if any run fails.
+pub fn measure<T, F>(runs: usize, mut f: F) -> Result<Stats, Error>
+where
+    F: FnMut() -> Result<T, Error>,
+{
+    f()?;
+
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let result = f();
+        samples.push(start.elapsed());
+        result?;
+    }
+
+    Ok(Stats::from_samples(&samples))
+}
+
+// Timings for one day: parsing its input and answering each part. A part
+// that isn't implemented has no timing.
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        let part = |stats: Option<Stats>| match stats {
+            Some(s) => s.to_json(),
+            None => "null".to_string(),
+        };
+
+        format!(
+            "{{\"day\":{},\"parse\":{},\"part1\":{},\"part2\":{}}}",
+            self.day,
+            self.parse.to_json(),
+            part(self.part1),
+            part(self.part2)
+        )
+    }
+}
+
+// Times parsing input and answering both parts of puzzle, runs times each.
+pub fn bench(day: u8, puzzle: &dyn Puzzle, input: &Input, runs: usize) -> Result<Report, Error> {
+    let parse = measure(runs, || puzzle.prepare(input))?;
+    let parsed = puzzle.prepare(input)?;
+
+    let mut parts = [None, None];
+    for (i, part) in [Part::One, Part::Two].iter().enumerate() {
+        parts[i] = match measure(runs, || parsed.solve(*part)) {
+            Ok(stats) => Some(stats),
+            Err(Error::NotImplemented) => None,
+            Err(why) => return Err(why),
+        };
+    }
+
+    Ok(Report {
+        day,
+        parse,
+        part1: parts[0],
+        part2: parts[1],
+    })
+}
+
+// Renders reports as one JSON document, tagged with whatever identifies
+// the run, e.g. the commit it was built from.
+pub fn reports_to_json(commit: Option<&str>, reports: &[Report]) -> String {
+    let mut json = String::from("{");
+    match commit {
+        Some(c) => write!(json, "\"commit\":\"{}\",", c).unwrap(),
+        None => json.push_str("\"commit\":null,"),
+    }
+
+    json.push_str("\"days\":[");
+    for (i, report) in reports.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+
+        json.push_str(&report.to_json());
+    }
+
+    json.push_str("]}");
+    json
+}
+
+// Where the latest timings of each day are kept. They only mean anything
+// on the machine that took them, so they live under target/.
+pub fn latest_path() -> PathBuf {
+    state_dir().join("bench.tsv")
+}
+
+// The median timings from a day's most recent benchmark, and when it ran.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Latest {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+    // Seconds since the epoch.
+    pub time: u64,
+}
+
+impl Latest {
+    // Parsing plus whichever parts are implemented.
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+// The latest benchmark of every day benchmarked so far, stored as
+// tab-separated day, then nanoseconds to parse and answer each part ("-"
+// if not implemented), then time, one day per line.
+#[derive(Debug, Default)]
+pub struct History(BTreeMap<u8, Latest>);
+
+impl History {
+    // Loads history from path. A missing file is just no history yet.
+    pub fn load(path: &Path) -> Result<History, Error> {
+        let text = match fs::read_to_string(path) {
+            Ok(t) => t,
+            Err(why) if why.kind() == io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(why) => return Err(why.into()),
+        };
+
+        History::parse(&text).map_err(|e| e.with_path(path))
+    }
+
+    fn parse(text: &str) -> Result<History, Error> {
+        let mut history = History::default();
+        for (i, line) in text.lines().enumerate() {
+            let n = i + 1;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() != 5 {
+                return Err(
+                    ParseError::new(n, 1, "expected day, parse, part 1, part 2 and time").into(),
+                );
+            }
+
+            let number = |field: &str| {
+                field
+                    .parse::<u64>()
+                    .map_err(|_| ParseError::at(n, line, field, "invalid number"))
+            };
+            let nanos = |field: &str| match field {
+                "-" => Ok(None),
+                f => number(f).map(|n| Some(Duration::from_nanos(n))),
+            };
+
+            let day = u8::try_from(number(fields[0])?)
+                .map_err(|_| ParseError::at(n, line, fields[0], "invalid day"))?;
+            history.0.insert(
+                day,
+                Latest {
+                    parse: Duration::from_nanos(number(fields[1])?),
+                    part1: nanos(fields[2])?,
+                    part2: nanos(fields[3])?,
+                    time: number(fields[4])?,
+                },
+            );
+        }
+
+        Ok(history)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let nanos = |d: Option<Duration>| match d {
+            Some(d) => d.as_nanos().to_string(),
+            None => "-".to_string(),
+        };
+
+        let mut text = String::from("# day\tparse\tpart1\tpart2\ttime\n");
+        for (day, l) in &self.0 {
+            writeln!(
+                text,
+                "{}\t{}\t{}\t{}\t{}",
+                day,
+                l.parse.as_nanos(),
+                nanos(l.part1),
+                nanos(l.part2),
+                l.time
+            )
+            .unwrap();
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, text)
+    }
+
+    // Replaces the day's latest timings with the medians in report.
+    pub fn update(&mut self, report: &Report) {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        self.0.insert(
+            report.day,
+            Latest {
+                parse: report.parse.median,
+                part1: report.part1.map(|s| s.median),
+                part2: report.part2.map(|s| s.median),
+                time,
+            },
+        );
+    }
+
+    pub fn get(&self, day: u8) -> Option<&Latest> {
+        self.0.get(&day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn from_samples_works() {
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(8), ms(6)]);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(5));
+        assert_eq!(stats.mean, ms(5));
+        // Deviations of 3, 1, 1 and 3 give a variance of 5.
+        assert_eq!(stats.stddev.as_micros(), 2236);
+    }
+
+    #[test]
+    fn from_samples_handles_odd_and_empty() {
+        assert_eq!(Stats::from_samples(&[ms(3), ms(1), ms(2)]).median, ms(2));
+        assert_eq!(Stats::from_samples(&[]).runs, 0);
+    }
+
+    #[test]
+    fn measure_stops_on_error() {
+        let mut calls = 0;
+        let result = measure(5, || {
+            calls += 1;
+            if calls > 2 {
+                Err(Error::invalid("boom"))
+            } else {
+                Ok(())
+            }
+        });
+
+        assert!(result.is_err());
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn to_json_works() {
+        let stats = Stats::from_samples(&[ms(1)]);
+        let report = Report {
+            day: 6,
+            parse: stats,
+            part1: Some(stats),
+            part2: None,
+        };
+
+        let s = "{\"runs\":1,\"min_ns\":1000000,\"median_ns\":1000000,\"mean_ns\":1000000,\"stddev_ns\":0}";
+        assert_eq!(
+            reports_to_json(Some("abc123"), &[report]),
+            format!(
+                "{{\"commit\":\"abc123\",\"days\":[{{\"day\":6,\"parse\":{},\"part1\":{},\"part2\":null}}]}}",
+                s, s
+            )
+        );
+    }
+
+    #[test]
+    fn history_keeps_latest_per_day() {
+        let stats = Stats::from_samples(&[ms(2)]);
+        let mut history = History::default();
+        history.update(&Report {
+            day: 6,
+            parse: Stats::from_samples(&[ms(9)]),
+            part1: None,
+            part2: None,
+        });
+        history.update(&Report {
+            day: 6,
+            parse: stats,
+            part1: Some(stats),
+            part2: None,
+        });
+
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.tsv", std::process::id()));
+        history.save(&path).unwrap();
+        let loaded = History::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let latest = loaded.get(6).unwrap();
+        assert_eq!(latest.part1, Some(ms(2)));
+        assert_eq!(latest.part2, None);
+        assert_eq!(latest.total(), ms(4));
+        assert_eq!(loaded.get(7), None);
+
+        match History::parse("262\t1\t-\t-\t0\n") {
+            Err(Error::Parse(e)) => assert_eq!(
+                (e.line, e.column, e.message.as_str()),
+                (1, 1, "invalid day")
+            ),
+            other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -1,3 +1,3 @@
 fn main() {
-    println!("Hello world!")
+    println!("day 17 not implemented")
 }
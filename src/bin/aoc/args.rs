@@ -0,0 +1,110 @@
+use advent_21::Part;
+
+// Flags and positional arguments left over after the subcommand name.
+pub struct Args {
+    positional: Vec<String>,
+    flags: Vec<(String, Option<String>)>,
+}
+
+impl Args {
+    // Splits raw arguments into flags and positionals. A flag listed in
+    // with_value takes the argument after it as its value.
+    pub fn parse(raw: &[String], with_value: &[&str]) -> Result<Args, String> {
+        let mut positional = vec![];
+        let mut flags = vec![];
+
+        let mut iter = raw.iter();
+        while let Some(arg) = iter.next() {
+            if !arg.starts_with("--") {
+                positional.push(arg.clone());
+                continue;
+            }
+
+            let name = arg.trim_start_matches("--").to_string();
+            if with_value.contains(&name.as_str()) {
+                match iter.next() {
+                    Some(value) => flags.push((name, Some(value.clone()))),
+                    None => return Err(format!("flag --{} expects a value", name)),
+                }
+            } else {
+                flags.push((name, None));
+            }
+        }
+
+        Ok(Args { positional, flags })
+    }
+
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+
+    pub fn has(&self, flag: &str) -> bool {
+        self.flags.iter().any(|(name, _)| name == flag)
+    }
+
+    pub fn value(&self, flag: &str) -> Option<&str> {
+        self.flags
+            .iter()
+            .find(|(name, _)| name == flag)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    // Fails on any flag not in known, so typos don't go unnoticed.
+    pub fn allow_only(&self, known: &[&str]) -> Result<(), String> {
+        for (name, _) in &self.flags {
+            if !known.contains(&name.as_str()) {
+                return Err(format!("unknown flag --{}", name));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("expected a day from 1 to 25, got '{}'", s)),
+    }
+}
+
+pub fn parse_part(s: &str) -> Result<Part, String> {
+    match s {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("expected part 1 or 2, got '{}'", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(raw: &[&str]) -> Vec<String> {
+        raw.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_works() {
+        let args = Args::parse(&strings(&["5", "--part", "2", "--all"]), &["part"]).unwrap();
+        assert_eq!(args.positional(), &["5".to_string()]);
+        assert_eq!(args.value("part"), Some("2"));
+        assert!(args.has("all"));
+        assert!(!args.has("verbose"));
+        assert!(args.allow_only(&["part", "all"]).is_ok());
+        assert!(args.allow_only(&["part"]).is_err());
+    }
+
+    #[test]
+    fn missing_value_fails() {
+        assert!(Args::parse(&strings(&["--part"]), &["part"]).is_err());
+    }
+
+    #[test]
+    fn parse_day_works() {
+        assert_eq!(parse_day("25"), Ok(25));
+        assert!(parse_day("0").is_err());
+        assert!(parse_day("26").is_err());
+        assert!(parse_day("five").is_err());
+    }
+}
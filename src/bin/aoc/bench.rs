@@ -0,0 +1,161 @@
+use crate::args::Args;
+use crate::table::Table;
+use crate::Failure;
+use advent_21::bench::{self, History, Report, Stats};
+use advent_21::registry;
+use advent_21::Source;
+use std::fs;
+use std::process::Command;
+use std::time::Duration;
+
+const DEFAULT_RUNS: usize = 20;
+
+// Times parse, part 1 and part 2 of the selected days, printing a table
+// of timings or, with --json, one JSON document to compare across runs.
+// Each day's medians are also kept as its latest timings, for status.
+pub fn bench(raw: &[String]) -> Result<bool, Failure> {
+    let args = Args::parse(raw, &["runs", "out"])?;
+    args.allow_only(&["runs", "json", "out", "all", "embedded"])?;
+
+    let days = crate::select_days_or_all(&args)?;
+
+    let runs = match args.value("runs") {
+        Some(r) => match r.parse::<usize>() {
+            Ok(r) if r > 0 => r,
+            _ => {
+                return Err(Failure::Usage(format!(
+                    "expected a positive number of runs, got '{}'",
+                    r
+                )))
+            }
+        },
+        None => DEFAULT_RUNS,
+    };
+
+    let source = if args.has("embedded") {
+        Source::Embedded
+    } else {
+        Source::Data
+    };
+
+    let mut reports = vec![];
+    let mut ok = true;
+    for entry in registry::entries() {
+        let puzzle = match &entry.puzzle {
+            Some(p) if days.contains(&entry.day) => p,
+            _ => continue,
+        };
+
+        let report = source
+            .read(entry.day)
+            .map_err(advent_21::Error::from)
+            .and_then(|input| bench::bench(entry.day, puzzle.as_ref(), &input, runs));
+
+        match report {
+            Ok(r) => reports.push(r),
+            Err(why) => {
+                eprintln!("day {}: {}", entry.day, why);
+                ok = false;
+            }
+        }
+    }
+
+    let path = bench::latest_path();
+    let mut history = History::load(&path).map_err(Failure::runtime)?;
+    for report in &reports {
+        history.update(report);
+    }
+    history
+        .save(&path)
+        .map_err(|e| Failure::Runtime(format!("failed to write {}: {}", path.display(), e)))?;
+
+    let json = bench::reports_to_json(commit().as_deref(), &reports);
+    if let Some(out) = args.value("out") {
+        fs::write(out, &json)
+            .map_err(|e| Failure::Runtime(format!("failed to write {}: {}", out, e)))?;
+    }
+
+    if args.has("json") {
+        println!("{}", json);
+    } else {
+        print!("{}", table(&reports));
+    }
+
+    Ok(ok)
+}
+
+fn table(reports: &[Report]) -> Table {
+    let mut table = Table::new(&["day", "stage", "runs", "min", "median", "mean", "stddev"]);
+    for r in reports {
+        let stages = [
+            ("parse", Some(r.parse)),
+            ("part 1", r.part1),
+            ("part 2", r.part2),
+        ];
+
+        for (stage, stats) in stages.iter() {
+            let mut row = vec![r.day.to_string(), stage.to_string()];
+            match stats {
+                Some(s) => row.extend(cells(s)),
+                None => row.extend(vec!["-".to_string(); 5]),
+            }
+
+            table.push(row);
+        }
+    }
+
+    table
+}
+
+fn cells(s: &Stats) -> Vec<String> {
+    vec![
+        s.runs.to_string(),
+        format_duration(s.min),
+        format_duration(s.median),
+        format_duration(s.mean),
+        format_duration(s.stddev),
+    ]
+}
+
+// Formats d in whichever unit keeps it readable, e.g. 12.35ms.
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{}ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.2}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+// The commit the tree is at, if git can tell us, so saved results can be
+// matched up with the code that produced them.
+fn commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_duration_works() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.35µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2_000)), "2.00s");
+    }
+}
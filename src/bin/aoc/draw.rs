@@ -0,0 +1,82 @@
+use crate::args::{self, Args};
+use crate::Failure;
+use advent_21::day05::Day5;
+use advent_21::{state_dir, Error, Solution};
+use std::fs;
+use std::path::PathBuf;
+
+// Renders a day's input as images to look at, for the days that have
+// something to draw: day 5 as a heatmap of its vent lines' overlaps and
+// a drawing of the lines themselves.
+pub fn draw(raw: &[String]) -> Result<bool, Failure> {
+    let args = Args::parse(raw, &["lines", "input", "out"])?;
+    args.allow_only(&["lines", "input", "out", "embedded"])?;
+
+    let day = match args.positional() {
+        [day] => args::parse_day(day)?,
+        _ => return Err(Failure::Usage("expected a single day".to_string())),
+    };
+    if day != 5 {
+        return Err(Failure::Usage(format!("day {} has nothing to draw", day)));
+    }
+
+    let source = crate::select_source(&args, &[day])?;
+    let filter = crate::select_filter(&args, &[day])?.unwrap_or_default();
+    let dir = match args.value("out") {
+        Some(dir) => PathBuf::from(dir),
+        None => state_dir(),
+    };
+
+    let solution = Day5 {
+        filter,
+        ..Day5::default()
+    };
+    let drawn = source
+        .read(day)
+        .map_err(Error::from)
+        .and_then(|input| solution.checked_parse(&input))
+        .and_then(|vents| Ok((solution.heatmap(&vents)?, solution.drawing(&vents))));
+    let (heatmap, drawing) = match drawn {
+        Ok(drawn) => drawn,
+        Err(why) => {
+            eprintln!("day {}: {}", day, why);
+            return Ok(false);
+        }
+    };
+
+    fs::create_dir_all(&dir).map_err(|e| Failure::Runtime(format!("{}: {}", dir.display(), e)))?;
+    let files = [
+        (dir.join(format!("day{:02}.ppm", day)), heatmap),
+        (
+            dir.join(format!("day{:02}.svg", day)),
+            drawing.to_string().into_bytes(),
+        ),
+    ];
+    for (path, bytes) in files.iter() {
+        fs::write(path, bytes)
+            .map_err(|e| Failure::Runtime(format!("{}: {}", path.display(), e)))?;
+        println!("wrote {}", path.display());
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_21::Input;
+
+    #[test]
+    fn checks_the_input_before_drawing() {
+        let solution = Day5::default();
+        let input = Input::new("vents.txt", "0,0 -> 2,2\n".to_string());
+        assert!(solution.checked_parse(&input).is_ok());
+
+        let input = Input::new("vents.txt", "0,0 -> 2,2\n0;0 -> 1,1\n".to_string());
+        match solution.checked_parse(&input) {
+            Err(why @ Error::Malformed(_)) => assert!(why.to_string().contains("vents.txt:2:2")),
+            Err(why) => panic!("expected malformed input, got {}", why),
+            Ok(_) => panic!("parsed malformed input"),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::args::{self, Args};
+use crate::table::Table;
+use crate::Failure;
+use advent_21::fetch::{Fetched, Fetcher};
+use advent_21::registry;
+use advent_21::{data_dir, Config};
+
+// Downloads the inputs of the selected days into the data directory,
+// skipping any already there. Reports false if any download failed.
+pub fn fetch(raw: &[String]) -> Result<bool, Failure> {
+    let args = Args::parse(raw, &[])?;
+    args.allow_only(&["all"])?;
+
+    let days = match (args.has("all"), args.positional()) {
+        (true, []) => (1..=registry::DAYS).collect(),
+        (false, [day]) => vec![args::parse_day(day)?],
+        _ => return Err(Failure::Usage("expected a single day or --all".to_string())),
+    };
+
+    let config = Config::load().map_err(Failure::runtime)?;
+    let fetcher = Fetcher::new(config, data_dir());
+
+    let mut table = Table::new(&["day", "input", "status"]);
+    let mut ok = true;
+    for day in days {
+        let (input, status) = match fetcher.fetch(day) {
+            Ok(Fetched::Cached(path)) => (path.display().to_string(), "cached".to_string()),
+            Ok(Fetched::Downloaded(path)) => (path.display().to_string(), "downloaded".to_string()),
+            Err(why) => {
+                ok = false;
+                ("-".to_string(), why.to_string())
+            }
+        };
+
+        table.push(vec![day.to_string(), input, status]);
+    }
+
+    print!("{}", table);
+    Ok(ok)
+}
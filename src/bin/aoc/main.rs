@@ -0,0 +1,268 @@
+mod args;
+mod bench;
+mod draw;
+mod fetch;
+mod scaffold;
+mod status;
+mod submit;
+mod table;
+mod verify;
+mod watch;
+
+use advent_21::day05::{Counting, Day5, LineFilter};
+use advent_21::registry::{self, Entry};
+use advent_21::{Error, Part, Source};
+use args::Args;
+use std::env;
+use std::fmt::Display;
+use std::process;
+use table::Table;
+
+const USAGE: &str = "usage:
+    aoc run <day> [--part 1|2] [--input <path>|-]    solve one day, or just one part of it
+    aoc run --all [--part 1|2] [--embedded]          solve every day
+    aoc run 5 [--lines <filter>]                     solve day 5 counting only some lines
+    aoc run 5 [--count grid|pairwise]                solve day 5 counting overlaps another way
+    aoc verify [<day>|--all]                         check answers against recorded ones
+    aoc record <day> [--part 1|2] [--input <name>]   record answers once confirmed correct
+    aoc bench [<day>|--all] [--runs <n>] [--json] [--out <path>]
+                                                     time parsing and both parts
+    aoc watch <day> [--interval <ms>]                re-run tests and solution on every change
+    aoc status                                       show where every day stands
+    aoc draw 5 [--lines <filter>] [--out <dir>]      render day 5's vents as images
+    aoc fetch <day>|--all                            download inputs not already in data/
+    aoc submit <day> --part 1|2 [--answer <value>]   send an answer, by default the solved one
+    aoc new <day> [--module <name>]                  start a day from the template
+
+inputs are read from data/<day>.txt unless given --input, where - means
+stdin, or --embedded to use the inputs compiled into the binary. set
+AOC_DATA_DIR to read them from another directory.
+
+day 5's line filter is comma separated terms: one of any, axis or
+diagonal, and min=<n> to skip lines covering fewer than n points, e.g.
+--lines diagonal,min=3. it narrows both parts. overlaps are counted on
+a grid unless given --count pairwise, which works them out from each
+pair of lines instead.
+
+drawings go in target/aoc unless given --out: day05.ppm, a heatmap of
+how many lines cover each point, and day05.svg, the lines themselves.
+
+recorded answers live in data/answers.tsv, keyed by day, part and input
+name: \"input\" for data/<day>.txt, <name> for data/<day>.<name>.txt, or a
+path.
+
+fetching needs the site's session cookie, from AOC_SESSION or a line
+`session = <token>` in aoc.conf (or the file AOC_CONFIG names). inputs
+already downloaded are never fetched again, and requests are kept at
+least min_interval seconds apart (5 unless configured).
+
+submitting uses the same session. every guess and its verdict goes in
+target/aoc/guesses.tsv, and answers it shows can't be right aren't sent. a
+correct answer is recorded in data/answers.tsv.
+
+new days get a Solution skeleton, a module (\"model\" unless named), empty
+takeaways, data placeholders and an entry in the runner. days with anything
+beyond the original stub are left alone.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let outcome = match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify::verify(&args[1..]),
+        Some("record") => verify::record(&args[1..]),
+        Some("bench") => bench::bench(&args[1..]),
+        Some("watch") => watch::watch(&args[1..]),
+        Some("status") => status::status(&args[1..]),
+        Some("draw") => draw::draw(&args[1..]),
+        Some("fetch") => fetch::fetch(&args[1..]),
+        Some("submit") => submit::submit(&args[1..]),
+        Some("new") => scaffold::new_day(&args[1..]),
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            Ok(true)
+        }
+        Some(other) => Err(Failure::Usage(format!("unknown command '{}'", other))),
+        None => Err(Failure::Usage("expected a command".to_string())),
+    };
+
+    match outcome {
+        Ok(true) => (),
+        Ok(false) => process::exit(1),
+        Err(Failure::Usage(why)) => {
+            eprintln!("{}\n\n{}", why, USAGE);
+            process::exit(2);
+        }
+        Err(Failure::Runtime(why)) => {
+            eprintln!("{}", why);
+            process::exit(1);
+        }
+    }
+}
+
+// Why a command gave up: it was asked for wrongly, which the usage can
+// help with, or something went wrong while carrying it out. Argument
+// errors are plain strings, so they convert to Usage on their own.
+#[derive(Debug, PartialEq)]
+pub enum Failure {
+    Usage(String),
+    Runtime(String),
+}
+
+impl Failure {
+    pub fn runtime(why: impl Display) -> Failure {
+        Failure::Runtime(why.to_string())
+    }
+}
+
+impl From<String> for Failure {
+    fn from(why: String) -> Failure {
+        Failure::Usage(why)
+    }
+}
+
+// Solves the selected days and parts, printing one table row per part.
+// Reports false if any implemented day failed to read, parse or solve.
+fn run(raw: &[String]) -> Result<bool, Failure> {
+    let args = Args::parse(raw, &["part", "input", "lines", "count"])?;
+    args.allow_only(&["part", "all", "input", "embedded", "lines", "count"])?;
+
+    let parts = match args.value("part") {
+        Some(p) => vec![args::parse_part(p)?],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let days = select_days(&args)?;
+    let source = select_source(&args, &days)?;
+    let filter = select_filter(&args, &days)?;
+    let counting = select_counting(&args, &days)?;
+
+    let mut table = Table::new(&["day", "part", "answer"]);
+    let mut ok = true;
+    for mut entry in registry::entries() {
+        if !days.contains(&entry.day) {
+            continue;
+        }
+
+        if entry.day == 5 && (filter.is_some() || counting.is_some()) {
+            entry.puzzle = Some(Box::new(Day5 {
+                filter: filter.unwrap_or_default(),
+                counting: counting.unwrap_or_default(),
+            }));
+        }
+
+        let answers = match solve(&entry, &source, &parts) {
+            Ok(answers) => answers,
+            // Nothing got as far as a part, so the one outcome goes in
+            // every part's row.
+            Err(Error::NotImplemented) => parts
+                .iter()
+                .map(|p| (*p, Ok(Outcome::NotImplemented)))
+                .collect(),
+            Err(why) => parts.iter().map(|p| (*p, Err(why.to_string()))).collect(),
+        };
+
+        for (part, answer) in answers {
+            let cell = match answer {
+                Ok(a) => a.to_string(),
+                Err(why) => {
+                    ok = false;
+                    why
+                }
+            };
+
+            table.push(vec![entry.day.to_string(), part.to_string(), cell]);
+        }
+    }
+
+    print!("{}", table);
+    Ok(ok)
+}
+
+fn select_days(args: &Args) -> Result<Vec<u8>, String> {
+    match (args.has("all"), args.positional()) {
+        (true, []) => Ok((1..=registry::DAYS).collect()),
+        (false, [day]) => Ok(vec![args::parse_day(day)?]),
+        (true, _) => Err("expected either a day or --all, not both".to_string()),
+        (false, _) => Err("expected a single day or --all".to_string()),
+    }
+}
+
+// Like select_days, but no day at all means every day, --all or not.
+fn select_days_or_all(args: &Args) -> Result<Vec<u8>, String> {
+    match (args.has("all"), args.positional()) {
+        (_, []) => Ok((1..=registry::DAYS).collect()),
+        (false, [day]) => Ok(vec![args::parse_day(day)?]),
+        (true, [_]) => Err("expected either a day or --all, not both".to_string()),
+        (_, _) => Err("expected at most one day".to_string()),
+    }
+}
+
+fn select_source(args: &Args, days: &[u8]) -> Result<Source, String> {
+    match (args.value("input"), args.has("embedded")) {
+        (Some(_), true) => Err("expected either --input or --embedded, not both".to_string()),
+        (Some(_), false) if days.len() > 1 => {
+            Err("--input only works when solving a single day".to_string())
+        }
+        (Some(arg), false) => Ok(Source::from_arg(arg)),
+        (None, true) => Ok(Source::Embedded),
+        (None, false) => Ok(Source::Data),
+    }
+}
+
+fn select_filter(args: &Args, days: &[u8]) -> Result<Option<LineFilter>, String> {
+    match args.value("lines") {
+        Some(_) if days != [5] => Err("--lines only works when solving day 5".to_string()),
+        Some(spec) => spec.parse().map(Some).map_err(|e: Error| e.to_string()),
+        None => Ok(None),
+    }
+}
+
+fn select_counting(args: &Args, days: &[u8]) -> Result<Option<Counting>, String> {
+    match args.value("count") {
+        Some(_) if days != [5] => Err("--count only works when solving day 5".to_string()),
+        Some(how) => how.parse().map(Some).map_err(|e: Error| e.to_string()),
+        None => Ok(None),
+    }
+}
+
+// What solving a part came to, when it didn't fail.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Outcome {
+    Solved(String),
+    NotImplemented,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Solved(answer) => f.write_str(answer),
+            Outcome::NotImplemented => write!(f, "{}", Error::NotImplemented),
+        }
+    }
+}
+
+// Each part with its outcome, or why solving it failed.
+type Solved = Vec<(Part, Result<Outcome, String>)>;
+
+// Answers each of parts for entry, in order. The input is only read and
+// parsed once, failing as a whole if either step does. Parts that aren't
+// implemented yet come back as Outcome::NotImplemented, not an error.
+pub fn solve(entry: &Entry, source: &Source, parts: &[Part]) -> Result<Solved, Error> {
+    let puzzle = entry.puzzle.as_ref().ok_or(Error::NotImplemented)?;
+    let input = source.read(entry.day)?;
+    let parsed = puzzle.prepare(&input)?;
+
+    let mut answers = vec![];
+    for part in parts {
+        let answer = match parsed.solve(*part) {
+            Ok(a) => Ok(Outcome::Solved(a)),
+            Err(Error::NotImplemented) => Ok(Outcome::NotImplemented),
+            Err(why) => Err(why.to_string()),
+        };
+
+        answers.push((*part, answer));
+    }
+
+    Ok(answers)
+}
@@ -0,0 +1,295 @@
+use crate::args::{self, Args};
+use crate::Failure;
+use advent_21::data_dir;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Where `aoc new` registers days: once in lib.rs among the day modules
+// and once in the registry's list of solved days.
+const MARKER: &str = "// New days are added above here by `aoc new`.";
+
+const MODULE_TEMPLATE: &str = "pub mod {module};
+
+use crate::{Error, Expect, Input, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn expect(&self) -> Expect {
+        Expect::new()
+    }
+
+    fn parse(&self, input: &Input) -> Result<Self::Input, Error> {
+        Ok(input.lines().map(|(_, line)| line.to_string()).collect())
+    }
+
+    fn part1(&self, _: &Self::Input) -> Result<Self::Answer1, Error> {
+        Err(Error::NotImplemented)
+    }
+
+    fn part2(&self, _: &Self::Input) -> Result<Self::Answer2, Error> {
+        Err(Error::NotImplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Checks each data/{day}.example*.txt against its recorded answers.
+    #[test]
+    fn examples_match_recorded_answers() {
+        let failures = crate::check_examples({day}, &Day{day});
+        assert!(failures.is_empty(), \"\\n{}\", failures.join(\"\\n\"));
+    }
+}
+";
+
+const SUBMODULE_TEMPLATE: &str = "// Model for day {day}'s puzzle.
+";
+
+// Takeaways start out empty, to be written once the day is done.
+const TAKEAWAYS_TEMPLATE: &str = "";
+
+const MAIN_TEMPLATE: &str = "fn main() {
+    advent_21::run({day}, &advent_21::day{dd}::Day{day});
+}
+";
+
+// Creates the files for a new day from templates and registers it in
+// the runner. Refuses to touch a day that has anything beyond the
+// original stub, so existing work is never overwritten.
+pub fn new_day(raw: &[String]) -> Result<bool, Failure> {
+    let args = Args::parse(raw, &["module"])?;
+    args.allow_only(&["module"])?;
+
+    let day = match args.positional() {
+        [day] => args::parse_day(day)?,
+        _ => return Err(Failure::Usage("expected a single day".to_string())),
+    };
+    let module = args.value("module").unwrap_or("model");
+    if !is_module_name(module) {
+        return Err(Failure::Usage(format!(
+            "'{}' is not a usable module name",
+            module
+        )));
+    }
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for path in scaffold(root, &data_dir(), day, module).map_err(Failure::Runtime)? {
+        println!("created {}", path.display());
+    }
+
+    Ok(true)
+}
+
+// Does the work of new_day against the crate at root and the data
+// directory data, returning every file written. Nothing is written
+// unless every check passes first.
+fn scaffold(root: &Path, data: &Path, day: u8, module: &str) -> Result<Vec<PathBuf>, String> {
+    let src = root.join("src");
+    let dir = src.join(format!("day{:02}", day));
+    if let Ok(mut entries) = fs::read_dir(&dir) {
+        if let Some(entry) = entries.next() {
+            let path = entry.map(|e| e.path()).unwrap_or_else(|_| dir.clone());
+            return Err(format!("{} already exists", path.display()));
+        }
+    }
+
+    let main = src.join("bin").join(day.to_string()).join("main.rs");
+    if let Ok(text) = fs::read_to_string(&main) {
+        if text.trim() != stub(day).trim() {
+            return Err(format!("{} already has work in it", main.display()));
+        }
+    }
+
+    let lib_path = src.join("lib.rs");
+    let lib = read(&lib_path)?;
+    let declaration = format!("pub mod day{:02};", day);
+    if lib.lines().any(|l| l.trim() == declaration) {
+        return Err(format!("day {} is already registered", day));
+    }
+    let lib = insert_above_marker(&lib, &declaration)?;
+
+    let registry_path = src.join("registry.rs");
+    let registry = insert_above_marker(
+        &read(&registry_path)?,
+        &format!("({}, Box::new(crate::day{:02}::Day{})),", day, day, day),
+    )?;
+
+    let fill = |template: &str| {
+        template
+            .replace("{dd}", &format!("{:02}", day))
+            .replace("{day}", &day.to_string())
+            .replace("{module}", module)
+    };
+
+    let mut files = vec![
+        (dir.join("mod.rs"), fill(MODULE_TEMPLATE)),
+        (dir.join(format!("{}.rs", module)), fill(SUBMODULE_TEMPLATE)),
+        (dir.join("takeaways.md"), fill(TAKEAWAYS_TEMPLATE)),
+        (main, fill(MAIN_TEMPLATE)),
+        (lib_path, lib),
+        (registry_path, registry),
+    ];
+
+    // Data placeholders are only created when missing; an input already
+    // downloaded is left as it is.
+    for name in [format!("{}.txt", day), format!("{}.example.txt", day)].iter() {
+        let path = data.join(name);
+        if !path.exists() {
+            files.push((path, String::new()));
+        }
+    }
+
+    let mut written = vec![];
+    for (path, text) in files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("{}: {}", parent.display(), e))?;
+        }
+        fs::write(&path, text).map_err(|e| format!("{}: {}", path.display(), e))?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+// What every unimplemented day's main.rs started out as.
+fn stub(day: u8) -> String {
+    format!(
+        "fn main() {{\n    println!(\"day {} not implemented\")\n}}\n",
+        day
+    )
+}
+
+fn is_module_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let first_ok = matches!(chars.next(), Some(c) if c.is_ascii_lowercase());
+    first_ok
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && name != "tests"
+}
+
+// Adds line to source just above its one marker, with the marker's
+// indentation.
+fn insert_above_marker(source: &str, line: &str) -> Result<String, String> {
+    let markers = source.lines().filter(|l| l.trim() == MARKER).count();
+    if markers != 1 {
+        return Err(format!(
+            "expected one '{}' marker, found {}",
+            MARKER, markers
+        ));
+    }
+
+    let mut out = String::new();
+    for l in source.lines() {
+        if l.trim() == MARKER {
+            out.push_str(&l[..l.len() - l.trim_start().len()]);
+            out.push_str(line);
+            out.push('\n');
+        }
+
+        out.push_str(l);
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A throwaway crate with just the parts scaffold touches, where only
+    // day 1 is registered and day 9 is still a stub.
+    fn fake_crate(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/bin/9")).unwrap();
+        fs::create_dir_all(root.join("data")).unwrap();
+        fs::write(root.join("src/bin/9/main.rs"), stub(9)).unwrap();
+
+        let lib = format!("mod error;\n\npub mod day01;\n{}\n", MARKER);
+        fs::write(root.join("src/lib.rs"), lib).unwrap();
+        let registry = format!(
+            "    let solved = vec![\n        (1, Box::new(crate::day01::Day1)),\n        {}\n    ];\n",
+            MARKER
+        );
+        fs::write(root.join("src/registry.rs"), registry).unwrap();
+        root
+    }
+
+    #[test]
+    fn scaffolds_and_registers_stub_day() {
+        let root = fake_crate("stub");
+        let written = scaffold(&root, &root.join("data"), 9, "caves").unwrap();
+        assert_eq!(written.len(), 8);
+
+        let module = fs::read_to_string(root.join("src/day09/mod.rs")).unwrap();
+        assert!(module.starts_with("pub mod caves;"));
+        assert!(module.contains("crate::check_examples(9, &Day9)"));
+        assert!(root.join("src/day09/caves.rs").exists());
+        let takeaways = fs::read_to_string(root.join("src/day09/takeaways.md")).unwrap();
+        assert!(takeaways.is_empty());
+        assert!(root.join("data/9.example.txt").exists());
+
+        let main = fs::read_to_string(root.join("src/bin/9/main.rs")).unwrap();
+        assert!(main.contains("advent_21::run(9, &advent_21::day09::Day9);"));
+
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub mod day09;\n// New days"));
+        let registry = fs::read_to_string(root.join("src/registry.rs")).unwrap();
+        assert!(registry.contains("        (9, Box::new(crate::day09::Day9)),\n"));
+
+        // Running it again finds the work and leaves it alone.
+        assert!(scaffold(&root, &root.join("data"), 9, "caves").is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn refuses_to_overwrite_work() {
+        let root = fake_crate("work");
+        fs::write(root.join("src/bin/9/main.rs"), "fn main() {}\n").unwrap();
+        assert!(scaffold(&root, &root.join("data"), 9, "model").is_err());
+
+        fs::write(root.join("src/bin/9/main.rs"), stub(9)).unwrap();
+        fs::create_dir_all(root.join("src/day09")).unwrap();
+        fs::write(root.join("src/day09/takeaways.md"), "notes").unwrap();
+        assert!(scaffold(&root, &root.join("data"), 9, "model").is_err());
+
+        assert_eq!(
+            fs::read_to_string(root.join("src/day09/takeaways.md")).unwrap(),
+            "notes"
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn keeps_existing_input() {
+        let root = fake_crate("input");
+        fs::write(root.join("data/9.txt"), "2199943210\n").unwrap();
+        scaffold(&root, &root.join("data"), 9, "model").unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("data/9.txt")).unwrap(),
+            "2199943210\n"
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn rejects_already_registered_day() {
+        let root = fake_crate("registered");
+        assert!(scaffold(&root, &root.join("data"), 1, "model").is_err());
+        assert!(insert_above_marker("mod day01;\n", "mod day09;").is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,148 @@
+use crate::args::Args;
+use crate::bench::format_duration;
+use crate::table::Table;
+use crate::Failure;
+use crate::Outcome;
+use advent_21::bench::{self, History};
+use advent_21::registry::{self, Entry};
+use advent_21::{answers_path, data_dir, examples, Answers, Part};
+use std::fs;
+use std::path::Path;
+
+// Prints where every day stands: which parts are solved, what data and
+// tests it has, its recorded answers and how long it last took to run.
+pub fn status(raw: &[String]) -> Result<bool, Failure> {
+    let args = Args::parse(raw, &[])?;
+    args.allow_only(&[])?;
+    if !args.positional().is_empty() {
+        return Err(Failure::Usage("status takes no arguments".to_string()));
+    }
+
+    let answers = Answers::load(&answers_path()).map_err(Failure::runtime)?;
+    let history = History::load(&bench::latest_path()).map_err(Failure::runtime)?;
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+
+    let mut table = Table::new(&[
+        "day",
+        "parts",
+        "input",
+        "examples",
+        "tests",
+        "takeaways",
+        "answers",
+        "bench",
+    ]);
+    for entry in registry::entries() {
+        let day = entry.day;
+        let dir = src.join(format!("day{:02}", day));
+
+        let recorded: Vec<&str> = [Part::One, Part::Two]
+            .iter()
+            .map(|p| answers.get(day, *p, "input").unwrap_or("-"))
+            .collect();
+        let bench = match history.get(day) {
+            Some(latest) => format_duration(latest.total()),
+            None => "-".to_string(),
+        };
+
+        table.push(vec![
+            day.to_string(),
+            parts(&entry),
+            input(day),
+            count(examples(day).len()),
+            count(tests(&entry, &dir)),
+            yes_no(dir.join("takeaways.md").exists()),
+            recorded.join(" / "),
+            bench,
+        ]);
+    }
+
+    print!("{}", table);
+    Ok(true)
+}
+
+// Which parts of entry give an answer for any of its examples. Only the
+// examples are solved, being small, never the real input; parts that
+// fail on every example don't count. "stub" for days without a solution
+// at all, "?" if there are no examples to solve.
+fn parts(entry: &Entry) -> String {
+    if entry.puzzle.is_none() {
+        return "stub".to_string();
+    }
+
+    let examples = examples(entry.day);
+    if examples.is_empty() {
+        return "?".to_string();
+    }
+
+    let mut solved = vec![];
+    for example in examples {
+        for (part, outcome) in crate::solve(entry, &example, &[Part::One, Part::Two])
+            .into_iter()
+            .flatten()
+        {
+            if let Ok(Outcome::Solved(_)) = outcome {
+                solved.push(part);
+            }
+        }
+    }
+
+    let implemented: Vec<String> = [Part::One, Part::Two]
+        .iter()
+        .filter(|p| solved.contains(p))
+        .map(|p| p.to_string())
+        .collect();
+    if implemented.is_empty() {
+        "-".to_string()
+    } else {
+        implemented.join(",")
+    }
+}
+
+// Whether the day's input is there, and not just an empty placeholder.
+fn input(day: u8) -> String {
+    match fs::metadata(data_dir().join(format!("{}.txt", day))) {
+        Ok(m) if m.len() > 0 => "ok",
+        Ok(_) => "empty",
+        Err(_) => "missing",
+    }
+    .to_string()
+}
+
+// Counts the tests covering a day: those in its module, plus the
+// integration test in tests/examples.rs, which checks the examples of
+// every day with a solution.
+fn tests(entry: &Entry, dir: &Path) -> usize {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let checked = entry.puzzle.is_some()
+        && !examples(entry.day).is_empty()
+        && root.join("tests").join("examples.rs").exists();
+
+    module_tests(dir) + if checked { 1 } else { 0 }
+}
+
+fn module_tests(dir: &Path) -> usize {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return 0,
+    };
+
+    entries
+        .flatten()
+        .filter(|e| e.path().extension().is_some_and(|x| x == "rs"))
+        .filter_map(|e| fs::read_to_string(e.path()).ok())
+        .map(|text| text.matches("#[test]").count())
+        .sum()
+}
+
+fn count(n: usize) -> String {
+    if n == 0 {
+        "-".to_string()
+    } else {
+        n.to_string()
+    }
+}
+
+fn yes_no(b: bool) -> String {
+    if b { "yes" } else { "no" }.to_string()
+}
@@ -0,0 +1,78 @@
+use crate::args::{self, Args};
+use crate::{Failure, Outcome};
+use advent_21::fetch::Fetcher;
+use advent_21::registry;
+use advent_21::submit::{guesses_path, Guesses, Submission, Verdict};
+use advent_21::{answers_path, data_dir, Answers, Config, Source};
+
+// Submits one part's answer to the site: the one given, or else the one
+// the day's solution finds for its input. Every verdict is kept in the
+// guess history, and a correct answer is recorded as the expected one.
+// Reports false unless the answer was accepted.
+pub fn submit(raw: &[String]) -> Result<bool, Failure> {
+    let args = Args::parse(raw, &["part", "answer"])?;
+    args.allow_only(&["part", "answer"])?;
+
+    let day = match args.positional() {
+        [day] => args::parse_day(day)?,
+        _ => return Err(Failure::Usage("expected a single day".to_string())),
+    };
+    let part = match args.value("part") {
+        Some(p) => args::parse_part(p)?,
+        None => return Err(Failure::Usage("expected the --part to submit".to_string())),
+    };
+
+    let answer = match args.value("answer") {
+        Some(a) => a.to_string(),
+        None => {
+            let entry = registry::entries()
+                .into_iter()
+                .find(|e| e.day == day)
+                .unwrap();
+            let solved = crate::solve(&entry, &Source::Data, &[part]);
+            match solved.map_err(Failure::runtime)?.remove(0).1 {
+                Ok(Outcome::NotImplemented) => {
+                    return Err(Failure::Runtime(format!(
+                        "day {} part {} has no solution to submit",
+                        day, part
+                    )))
+                }
+                Ok(Outcome::Solved(a)) => a,
+                Err(why) => {
+                    return Err(Failure::Runtime(format!(
+                        "day {} part {}: {}",
+                        day, part, why
+                    )))
+                }
+            }
+        }
+    };
+
+    let path = guesses_path();
+    let mut guesses = Guesses::load(&path).map_err(Failure::runtime)?;
+    let fetcher = Fetcher::new(Config::load().map_err(Failure::runtime)?, data_dir());
+
+    let submitted = advent_21::submit::submit(&fetcher, &mut guesses, day, part, &answer);
+    let verdict = match submitted.map_err(Failure::runtime)? {
+        Submission::Refused(why) => {
+            println!(
+                "day {} part {}: not submitting {}, {}",
+                day, part, answer, why
+            );
+            return Ok(false);
+        }
+        Submission::Judged(verdict) => verdict,
+    };
+
+    guesses.save(&path).map_err(Failure::runtime)?;
+    println!("day {} part {}: {} is {}", day, part, answer, verdict);
+
+    if verdict == Verdict::Correct {
+        let path = answers_path();
+        let mut answers = Answers::load(&path).map_err(Failure::runtime)?;
+        answers.record(day, part, "input", &answer);
+        answers.save(&path).map_err(Failure::runtime)?;
+    }
+
+    Ok(matches!(verdict, Verdict::Correct | Verdict::AlreadySolved))
+}
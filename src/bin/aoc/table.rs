@@ -0,0 +1,106 @@
+use std::fmt::{Display, Write};
+use std::iter;
+
+// Plain text table with columns padded to their widest cell. A cell of
+// several lines, like a malformed input's list of problems, shows only
+// its first in the table, marked with a note giving the rest after it.
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Table {
+        Table {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: vec![],
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut notes: Vec<&str> = vec![];
+        let mut rows: Vec<Vec<String>> = vec![];
+        for row in &self.rows {
+            let mut cells = vec![];
+            for cell in row {
+                match cell.split_once('\n') {
+                    Some((first, _)) => {
+                        let n = match notes.iter().position(|note| note == cell) {
+                            Some(i) => i + 1,
+                            None => {
+                                notes.push(cell);
+                                notes.len()
+                            }
+                        };
+                        cells.push(format!("{} [{}]", first, n));
+                    }
+                    None => cells.push(cell.clone()),
+                }
+            }
+            rows.push(cells);
+        }
+
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.len()).collect();
+        for row in &rows {
+            for (i, cell) in row.iter().enumerate() {
+                if i < widths.len() && cell.chars().count() > widths[i] {
+                    widths[i] = cell.chars().count();
+                }
+            }
+        }
+
+        let mut s = String::new();
+        let rule: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        let all = iter::once(&self.headers)
+            .chain(iter::once(&rule))
+            .chain(rows.iter());
+        for row in all {
+            let mut line = String::new();
+            for (i, cell) in row.iter().enumerate() {
+                write!(line, "{:<width$}  ", cell, width = widths[i])?;
+            }
+
+            writeln!(s, "{}", line.trim_end())?;
+        }
+
+        for (i, note) in notes.iter().enumerate() {
+            writeln!(s, "\n[{}] {}", i + 1, note)?;
+        }
+
+        f.write_str(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notes_cells_of_several_lines() {
+        let mut table = Table::new(&["day", "answer"]);
+        let malformed = "malformed input, 2 problem(s):\n    1:1: bad\n    2:1: bad";
+        table.push(vec!["3".to_string(), malformed.to_string()]);
+        table.push(vec!["3".to_string(), malformed.to_string()]);
+        table.push(vec!["4".to_string(), "12".to_string()]);
+
+        assert_eq!(
+            table.to_string(),
+            "day  answer
+---  ----------------------------------
+3    malformed input, 2 problem(s): [1]
+3    malformed input, 2 problem(s): [1]
+4    12
+
+[1] malformed input, 2 problem(s):
+    1:1: bad
+    2:1: bad
+"
+        );
+    }
+}
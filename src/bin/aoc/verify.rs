@@ -0,0 +1,133 @@
+use crate::args::{self, Args};
+use crate::table::Table;
+use crate::{Failure, Outcome};
+use advent_21::registry;
+use advent_21::{answers_path, Answers, Part, Source};
+
+// Solves every implemented day against each input it has recorded
+// answers for, plus its main input, and compares. Prints the comparison
+// as a table and reports false on any mismatch or failure.
+pub fn verify(raw: &[String]) -> Result<bool, Failure> {
+    let args = Args::parse(raw, &[])?;
+    args.allow_only(&["all"])?;
+
+    let days = crate::select_days_or_all(&args)?;
+
+    let answers = Answers::load(&answers_path()).map_err(Failure::runtime)?;
+
+    let mut table = Table::new(&["day", "part", "input", "expected", "actual", "status"]);
+    let mut mismatches = 0;
+    for entry in registry::entries() {
+        if !days.contains(&entry.day) || entry.puzzle.is_none() {
+            continue;
+        }
+
+        let mut inputs = vec!["input".to_string()];
+        for (key, _) in answers.iter() {
+            if key.day == entry.day && !inputs.contains(&key.input) {
+                inputs.push(key.input.clone());
+            }
+        }
+
+        for input in inputs {
+            let source = Source::from_name(&input);
+            let solved = crate::solve(&entry, &source, &[Part::One, Part::Two]);
+
+            for part in [Part::One, Part::Two].iter() {
+                let expected = answers.get(entry.day, *part, &input);
+                let actual = match &solved {
+                    Ok(solved) => solved.iter().find(|(p, _)| p == part).unwrap().1.clone(),
+                    Err(why) => Err(why.to_string()),
+                };
+
+                let status = match (expected, &actual) {
+                    (_, Ok(Outcome::NotImplemented)) => "skipped",
+                    (None, Ok(_)) => "unrecorded",
+                    (Some(e), Ok(Outcome::Solved(a))) if e == a => "ok",
+                    (Some(_), Ok(_)) => "MISMATCH",
+                    (_, Err(_)) => "FAILED",
+                };
+
+                if status == "MISMATCH" || status == "FAILED" {
+                    mismatches += 1;
+                }
+
+                table.push(vec![
+                    entry.day.to_string(),
+                    part.to_string(),
+                    input.clone(),
+                    expected.unwrap_or("-").to_string(),
+                    match actual {
+                        Ok(a) => a.to_string(),
+                        Err(why) => why,
+                    },
+                    status.to_string(),
+                ]);
+            }
+        }
+    }
+
+    print!("{}", table);
+    if mismatches > 0 {
+        println!("\n{} answer(s) didn't match what's recorded", mismatches);
+    }
+
+    Ok(mismatches == 0)
+}
+
+// Solves a day and records its answers as the expected ones from now on.
+// Meant for answers already confirmed correct, e.g. accepted on the site.
+pub fn record(raw: &[String]) -> Result<bool, Failure> {
+    let args = Args::parse(raw, &["part", "input"])?;
+    args.allow_only(&["part", "input"])?;
+
+    let day = match args.positional() {
+        [day] => args::parse_day(day)?,
+        _ => return Err(Failure::Usage("expected a single day".to_string())),
+    };
+
+    let parts = match args.value("part") {
+        Some(p) => vec![args::parse_part(p)?],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let source = Source::from_name(args.value("input").unwrap_or("input"));
+    let input = source.name();
+
+    let entry = registry::entries()
+        .into_iter()
+        .find(|e| e.day == day)
+        .unwrap();
+    let solved = crate::solve(&entry, &source, &parts).map_err(Failure::runtime)?;
+
+    let path = answers_path();
+    let mut answers = Answers::load(&path).map_err(Failure::runtime)?;
+
+    let mut table = Table::new(&["day", "part", "input", "answer", "previous"]);
+    let mut ok = true;
+    for (part, answer) in solved {
+        let answer = match answer {
+            Ok(Outcome::NotImplemented) => continue,
+            Ok(Outcome::Solved(a)) => a,
+            Err(why) => {
+                eprintln!("day {} part {}: {}", day, part, why);
+                ok = false;
+                continue;
+            }
+        };
+
+        let previous = answers.record(day, part, &input, &answer);
+        table.push(vec![
+            day.to_string(),
+            part.to_string(),
+            input.clone(),
+            answer,
+            previous.unwrap_or_else(|| "-".to_string()),
+        ]);
+    }
+
+    answers.save(&path).map_err(Failure::runtime)?;
+    print!("{}", table);
+
+    Ok(ok)
+}
@@ -0,0 +1,250 @@
+use crate::args::{self, Args};
+use crate::Failure;
+use advent_21::data_dir;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const DEFAULT_INTERVAL_MS: u64 = 500;
+
+// When each watched file was last modified.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+// Each part's answer line from a run, keyed by part, e.g. "1" => "1557".
+type Answers = BTreeMap<String, String>;
+
+// Polls the crate's sources and the day's data files, re-running its
+// tests and solution whenever any of them change, until interrupted. After
+// each run, prints how the answers differ from the run before.
+pub fn watch(raw: &[String]) -> Result<bool, Failure> {
+    let args = Args::parse(raw, &["interval"])?;
+    args.allow_only(&["interval"])?;
+
+    let day = match args.positional() {
+        [day] => args::parse_day(day)?,
+        _ => return Err(Failure::Usage("expected a single day".to_string())),
+    };
+
+    let interval = match args.value("interval") {
+        Some(ms) => match ms.parse::<u64>() {
+            Ok(ms) if ms > 0 => Duration::from_millis(ms),
+            _ => {
+                return Err(Failure::Usage(format!(
+                    "expected a positive interval in ms, got '{}'",
+                    ms
+                )))
+            }
+        },
+        None => Duration::from_millis(DEFAULT_INTERVAL_MS),
+    };
+
+    // Everything under src/, as a day can depend on any shared module.
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    println!(
+        "watching {} and data/{}*.txt, ctrl-c to stop",
+        src.display(),
+        day
+    );
+
+    let mut seen = Snapshot::new();
+    let mut previous: Option<Answers> = None;
+    loop {
+        let now = snapshot(&src, &data_dir(), day);
+        if now != seen {
+            seen = now;
+            previous = Some(run_once(day, previous.as_ref()));
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+// Runs the day's tests then its solution, printing the test outcome and
+// the answers compared with previous. Returns the answers.
+fn run_once(day: u8, previous: Option<&Answers>) -> Answers {
+    println!("\n--- day {} ---", day);
+
+    let tests = Command::new(env!("CARGO"))
+        .args(["test", "--quiet", "--lib", &format!("day{:02}::", day)])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .stdin(Stdio::null())
+        .output();
+    match tests {
+        Ok(out) if out.status.success() => println!("tests: ok"),
+        Ok(out) => {
+            println!("tests: FAILED");
+            print!("{}", String::from_utf8_lossy(&out.stdout));
+            eprint!("{}", String::from_utf8_lossy(&out.stderr));
+        }
+        Err(why) => println!("tests: failed to run cargo: {}", why),
+    }
+
+    let solution = Command::new(env!("CARGO"))
+        .args(["run", "--quiet", "--bin", &day.to_string()])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .stdin(Stdio::null())
+        .output();
+    let answers = match solution {
+        Ok(out) => {
+            eprint!("{}", String::from_utf8_lossy(&out.stderr));
+            parse_answers(&String::from_utf8_lossy(&out.stdout))
+        }
+        Err(why) => {
+            println!("solution: failed to run cargo: {}", why);
+            Answers::new()
+        }
+    };
+
+    for line in diff(previous, &answers) {
+        println!("{}", line);
+    }
+
+    answers
+}
+
+// Modification times of every file under src, however deep, and of
+// day's input and examples in data. Files that vanish simply drop out,
+// which also counts as a change.
+fn snapshot(src: &Path, data: &Path, day: u8) -> Snapshot {
+    let mut files: Vec<PathBuf> = vec![];
+    let mut dirs = vec![src.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for path in fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|e| e.path())
+        {
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+
+    let input = format!("{}.txt", day);
+    let examples = format!("{}.example", day);
+    if let Ok(entries) = fs::read_dir(data) {
+        files.extend(entries.flatten().map(|e| e.path()).filter(|p| {
+            let name = p.file_name().unwrap_or_default().to_string_lossy();
+            name == input || (name.starts_with(&examples) && name.ends_with(".txt"))
+        }));
+    }
+
+    files
+        .into_iter()
+        .filter_map(|p| {
+            let modified = fs::metadata(&p).and_then(|m| m.modified()).ok()?;
+            Some((p, modified))
+        })
+        .collect()
+}
+
+// Picks the "part N: answer" lines out of a day's output.
+fn parse_answers(stdout: &str) -> Answers {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) = line.strip_prefix("part ")?.split_once(": ")?;
+            Some((part.to_string(), answer.to_string()))
+        })
+        .collect()
+}
+
+// One line per part describing its answer, and how it changed since
+// previous if there was a previous run.
+fn diff(previous: Option<&Answers>, current: &Answers) -> Vec<String> {
+    let mut parts: Vec<&String> = current.keys().collect();
+    if let Some(previous) = previous {
+        parts.extend(previous.keys().filter(|p| !current.contains_key(*p)));
+    }
+    parts.sort();
+
+    if parts.is_empty() {
+        return vec!["no answers".to_string()];
+    }
+
+    parts
+        .into_iter()
+        .map(|part| {
+            let now = current.get(part).map(|a| a.as_str()).unwrap_or("-");
+            match previous.map(|p| p.get(part).map(|a| a.as_str()).unwrap_or("-")) {
+                None => format!("part {}: {}", part, now),
+                Some(was) if was == now => format!("part {}: {} (unchanged)", part, now),
+                Some(was) => format!("part {}: {} (was {})", part, now, was),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(pairs: &[(&str, &str)]) -> Answers {
+        pairs
+            .iter()
+            .map(|(p, a)| (p.to_string(), a.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn parse_answers_skips_other_output() {
+        let out = "Compiling...\npart 1: 470\npart 2: not implemented\n";
+        assert_eq!(
+            parse_answers(out),
+            answers(&[("1", "470"), ("2", "not implemented")])
+        );
+    }
+
+    #[test]
+    fn diff_shows_changes_since_previous() {
+        let before = answers(&[("1", "470"), ("2", "not implemented")]);
+        let after = answers(&[("1", "470"), ("2", "989396")]);
+
+        assert_eq!(
+            diff(None, &before),
+            vec!["part 1: 470", "part 2: not implemented"]
+        );
+        assert_eq!(
+            diff(Some(&before), &after),
+            vec![
+                "part 1: 470 (unchanged)",
+                "part 2: 989396 (was not implemented)"
+            ]
+        );
+        assert_eq!(
+            diff(Some(&after), &answers(&[("1", "470")])),
+            vec!["part 1: 470 (unchanged)", "part 2: - (was 989396)"]
+        );
+    }
+
+    #[test]
+    fn snapshot_sees_nested_sources() {
+        let root = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/grid")).unwrap();
+        fs::create_dir_all(root.join("data")).unwrap();
+        fs::write(root.join("src/lib.rs"), "").unwrap();
+        fs::write(root.join("src/grid/mod.rs"), "").unwrap();
+        fs::write(root.join("data/5.txt"), "").unwrap();
+        fs::write(root.join("data/6.txt"), "").unwrap();
+
+        let seen = snapshot(&root.join("src"), &root.join("data"), 5);
+        let mut files: Vec<&PathBuf> = seen.keys().collect();
+        files.sort();
+        assert_eq!(
+            files,
+            vec![
+                &root.join("data/5.txt"),
+                &root.join("src/grid/mod.rs"),
+                &root.join("src/lib.rs")
+            ]
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{Error, ParseError, Result};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// Points at a config file other than aoc.conf in the crate root.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+// Overrides the session token from the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+pub fn config_path() -> PathBuf {
+    match env::var_os(CONFIG_VAR) {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.conf"),
+    }
+}
+
+// Settings for talking to the puzzle site, read from lines of
+// `key = value` with # comments, e.g.
+//
+//     session = 53616c7465645f5f...
+//     min_interval = 5
+//
+// None of them are required until something needs them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    // The site's session cookie, copied out of a logged in browser.
+    pub session: Option<String>,
+    // The year's root, e.g. https://adventofcode.com/2021.
+    pub base_url: String,
+    pub user_agent: String,
+    // The least time allowed between two requests to the site.
+    pub min_interval: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            session: None,
+            base_url: "https://adventofcode.com/2021".to_string(),
+            user_agent: "advent_21 (github.com/rchilly/aoc_21)".to_string(),
+            min_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+impl Config {
+    // Reads the config file, if there is one, then applies the
+    // environment on top.
+    pub fn load() -> Result<Config> {
+        let path = config_path();
+        let mut config = match fs::read_to_string(&path) {
+            Ok(text) => Config::parse(&text).map_err(|e| Error::Config(e.with_path(&path)))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        if let Some(session) = env::var_os(SESSION_VAR) {
+            config.session = Some(session.to_string_lossy().trim().to_string());
+        }
+
+        Ok(config)
+    }
+
+    pub fn parse(text: &str) -> std::result::Result<Config, ParseError> {
+        let mut config = Config::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ParseError::new(i + 1, 1, "expected key = value"))?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value.trim_end_matches('/').to_string(),
+                "user_agent" => config.user_agent = value,
+                "min_interval" => {
+                    let secs: f64 = value.parse().map_err(|_| {
+                        ParseError::new(i + 1, 1, format!("invalid seconds '{}'", value))
+                    })?;
+                    config.min_interval = Duration::from_secs_f64(secs.max(0.0));
+                }
+                other => {
+                    return Err(ParseError::new(
+                        i + 1,
+                        1,
+                        format!("unknown key '{}'", other),
+                    ))
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    // The session token, or why there isn't one.
+    pub fn session(&self) -> Result<&str> {
+        self.session.as_deref().ok_or_else(|| {
+            Error::remote(format!(
+                "no session token; set {} or session in {}",
+                SESSION_VAR,
+                config_path().display()
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_settings_over_defaults() {
+        let config = Config::parse(
+            "# where to fetch from\nsession = abc123\nbase_url = http://localhost:8000/\nmin_interval = 0.5\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, "http://localhost:8000");
+        assert_eq!(config.min_interval, Duration::from_millis(500));
+        assert_eq!(config.user_agent, Config::default().user_agent);
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let err = Config::parse("token = abc\n").unwrap_err();
+        assert_eq!(err.to_string(), "1:1: unknown key 'token'");
+        assert_eq!(
+            Error::Config(err.with_path(Path::new("aoc.conf"))).to_string(),
+            "invalid config: aoc.conf:1:1: unknown key 'token'"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{Error, Expect, Input, Solution};
+use std::str::FromStr;
+
+#[derive(Debug)]
+enum Direction {
+    Up,
+    Down,
+    Forward,
+}
+
+#[derive(Debug)]
+pub struct Command {
+    dir: Direction,
+    delta: u32,
+}
+
+impl Command {
+    // Applies the command the way part 1 reads it, with up and down
+    // changing depth directly and no notion of aim.
+    fn shift(&self, p: Position) -> Position {
+        match self.dir {
+            Direction::Up => Position {
+                y: p.y - self.delta,
+                ..p
+            },
+            Direction::Down => Position {
+                y: p.y + self.delta,
+                ..p
+            },
+            Direction::Forward => Position {
+                x: p.x + self.delta,
+                ..p
+            },
+        }
+    }
+
+    fn transform(&self, p: Position) -> Position {
+        match self.dir {
+            Direction::Up => Position {
+                x: p.x,
+                y: p.y,
+                aim: p.aim - self.delta,
+            },
+            Direction::Down => Position {
+                x: p.x,
+                y: p.y,
+                aim: p.aim + self.delta,
+            },
+            Direction::Forward => Position {
+                x: p.x + self.delta,
+                y: p.y + self.delta * p.aim,
+                aim: p.aim,
+            },
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut strs = ("", "");
+
+        for (n, s) in s.split_ascii_whitespace().enumerate() {
+            match n {
+                0 => strs.0 = s,
+                1 => strs.1 = s,
+                _ => return Err(Error::invalid("too many whitespace delimited substrings")),
+            }
+        }
+
+        let dir = strs.0.parse::<Direction>()?;
+        let delta = strs
+            .1
+            .parse::<u32>()
+            .map_err(|_| Error::invalid("invalid delta"))?;
+
+        Ok(Command { dir, delta })
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "up" => Ok(Direction::Up),
+            "down" => Ok(Direction::Down),
+            "forward" => Ok(Direction::Forward),
+            _ => Err(Error::invalid("invalid direction")),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Position {
+    x: u32,
+    y: u32,
+    aim: u32,
+}
+
+impl Position {
+    fn product(&self) -> usize {
+        self.x as usize * self.y as usize
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Command>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn expect(&self) -> Expect {
+        Expect::new()
+            .non_empty()
+            .charset("abcdefghijklmnopqrstuvwxyz 0123456789")
+    }
+
+    fn parse(&self, input: &Input) -> Result<Self::Input, Error> {
+        // This invokes Command's implementation of the FromStr trait
+        // under the hood, for each line.
+        Ok(crate::parse_lines(input)?)
+    }
+
+    fn part1(&self, commands: &Self::Input) -> Result<Self::Answer1, Error> {
+        let mut position = Position::default();
+
+        for cmd in commands {
+            position = cmd.shift(position);
+        }
+
+        Ok(position.product())
+    }
+
+    fn part2(&self, commands: &Self::Input) -> Result<Self::Answer2, Error> {
+        let mut position = Position::default();
+
+        for cmd in commands {
+            position = cmd.transform(position);
+        }
+
+        Ok(position.product())
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{Error, Expect, Input, ParseError, Solution};
+
+// All numbers in the real input are 12-bit, and 5-bit in the example.
+// We'll use closest size u16 to hold their values, so up to 16 bits.
+const MAX_BITS: usize = 16;
+
+pub struct Report {
+    numbers: Vec<u16>,
+    bits: usize,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Report;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn expect(&self) -> Expect {
+        Expect::new().non_empty().charset("01").uniform_width()
+    }
+
+    fn parse(&self, input: &Input) -> Result<Self::Input, Error> {
+        let bits = input.lines().next().map(|(_, l)| l.len()).unwrap_or(0);
+
+        for (n, line) in input.lines() {
+            if line.len() != bits || bits > MAX_BITS {
+                return Err(ParseError::new(
+                    n,
+                    1,
+                    format!(
+                        "expected {}-bit numbers like line 1, up to {}",
+                        bits, MAX_BITS
+                    ),
+                )
+                .into());
+            }
+        }
+
+        let numbers = crate::parse_lines_with(input, |line| u16::from_str_radix(line, 2))?;
+
+        Ok(Report { numbers, bits })
+    }
+
+    fn part1(&self, report: &Self::Input) -> Result<Self::Answer1, Error> {
+        calculate_power_consumption(&report.numbers, report.bits)
+    }
+
+    fn part2(&self, report: &Self::Input) -> Result<Self::Answer2, Error> {
+        calculate_life_support_rating(&report.numbers, report.bits)
+    }
+}
+
+fn calculate_power_consumption(numbers: &[u16], bits: usize) -> Result<usize, Error> {
+    let gamma_rate = find_most_common_bits(numbers, bits)?;
+
+    // Must remove the leading 1's in positions above bits
+    // after the NOT, e.g. 16-13 when we just want a 12-bit number.
+    let epsilon_rate = !gamma_rate & (u16::MAX >> (MAX_BITS - bits));
+
+    Ok(epsilon_rate as usize * gamma_rate as usize)
+}
+
+// Tries to find the most common bits at each index of the numbers
+// in numbers and reports them as the single number they represent
+// in sequence. A tie at any index is an error.
+fn find_most_common_bits(numbers: &[u16], bits: usize) -> Result<u16, Error> {
+    let mut tallies = vec![0i32; bits];
+
+    for number in numbers {
+        // AND each bit in each 12-bit number to check
+        // if it's set and adjust the tally for that
+        // bit index accordingly.
+        for (i, tally) in tallies.iter_mut().enumerate() {
+            let bit_index = 0b1 << (bits - (i + 1));
+            match number & bit_index {
+                0 => *tally -= 1,
+                _ => *tally += 1,
+            }
+        }
+    }
+
+    let mut most_common_str = String::new();
+    for (i, t) in tallies.iter().enumerate() {
+        if *t == 0 {
+            return Err(Error::unsolvable(format!(
+                "got tie, expected clear winner between 0 vs. 1 for bit position {}",
+                i
+            )));
+        }
+
+        if *t > 0 {
+            most_common_str.push('1');
+        } else {
+            most_common_str.push('0');
+        }
+    }
+
+    u16::from_str_radix(&most_common_str, 2).map_err(|e| Error::invalid(e.to_string()))
+}
+
+fn calculate_life_support_rating(numbers: &[u16], bits: usize) -> Result<usize, Error> {
+    // I love that ? works here. Thanks to the implementation of Into<usize> for u16.
+    let o2_gen_rate = find_one_by_bit_criteria(numbers, bits, BitCriteria::MostCommonOrOne)?;
+    let co2_scrub_rate = find_one_by_bit_criteria(numbers, bits, BitCriteria::LeastCommonOrZero)?;
+
+    Ok(o2_gen_rate as usize * co2_scrub_rate as usize)
+}
+
+// Criteria for filtering down a list of numbers by evaluating
+// one bit index at a time.
+enum BitCriteria {
+    // Keep all numbers with most common bit at index, or with
+    // value 1 in case of tie.
+    MostCommonOrOne,
+    // Keep all numbers with least common bit at index, or with
+    // value 0 in case of tie.
+    LeastCommonOrZero,
+}
+
+fn find_one_by_bit_criteria(
+    numbers: &[u16],
+    bits: usize,
+    critera: BitCriteria,
+) -> Result<u16, Error> {
+    let mut copy = numbers.to_vec();
+
+    for n in 0..bits {
+        if copy.len() == 1 {
+            break;
+        }
+
+        let mut tally = 0;
+        let bit_index = 0b1 << (bits - 1 - n);
+        for number in copy.iter() {
+            match number & bit_index {
+                0 => tally -= 1,
+                _ => tally += 1,
+            }
+        }
+
+        let keep_zero = match critera {
+            BitCriteria::LeastCommonOrZero => tally >= 0,
+            BitCriteria::MostCommonOrOne => tally < 0,
+        };
+
+        if keep_zero {
+            copy.retain(|number: &u16| (number & bit_index) == 0);
+        } else {
+            copy.retain(|number: &u16| (number & bit_index) > 0);
+        }
+    }
+
+    if copy.len() != 1 {
+        return Err(Error::unsolvable(format!(
+            "{} numbers remained after filtering by criteria",
+            copy.len()
+        )));
+    }
+
+    copy.pop()
+        .ok_or_else(|| Error::unsolvable("no numbers to filter"))
+}
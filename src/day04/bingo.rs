@@ -0,0 +1,268 @@
+use crate::grid::Grid;
+use crate::Error;
+use std::fmt::{Result as FmtResult, *};
+use std::result::Result as StdResult;
+
+// A way to win: which marked numbers make a bingo.
+#[derive(Debug, Clone, Copy)]
+pub enum Pattern {
+    // Any full row.
+    Row,
+    // Any full column.
+    Column,
+    // Either corner to corner diagonal. Square boards only.
+    Diagonal,
+    FourCorners,
+    // Both diagonals at once. Square boards only.
+    X,
+    // Every number on the board.
+    Blackout,
+    // Anything else, judged from which cells are marked.
+    Custom(fn(&Grid<bool>) -> bool),
+}
+
+impl Pattern {
+    pub fn wins(&self, marked: &Grid<bool>) -> bool {
+        let (w, h) = (marked.width(), marked.height());
+        let down = || (0..w).all(|i| marked.get(i, i) == Some(&true));
+        let up = || (0..w).all(|i| marked.get(w - 1 - i, i) == Some(&true));
+
+        match self {
+            Pattern::Row => marked.rows().any(|row| row.iter().all(|m| *m)),
+            Pattern::Column => marked.columns().any(|mut c| c.all(|m| *m)),
+            Pattern::Diagonal => w == h && (down() || up()),
+            Pattern::X => w == h && down() && up(),
+            Pattern::FourCorners => [(0, 0), (w - 1, 0), (0, h - 1), (w - 1, h - 1)]
+                .iter()
+                .all(|(x, y)| marked.get(*x, *y) == Some(&true)),
+            Pattern::Blackout => marked.iter().all(|(_, m)| *m),
+            Pattern::Custom(wins) => wins(marked),
+        }
+    }
+}
+
+// How a game of bingo is played: the patterns that win, any one being
+// enough, and whether the centre square starts out marked.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    pub patterns: Vec<Pattern>,
+    pub free_centre: bool,
+}
+
+impl Default for Rules {
+    // Plain bingo: a full row or column wins.
+    fn default() -> Rules {
+        Rules {
+            patterns: vec![Pattern::Row, Pattern::Column],
+            free_centre: false,
+        }
+    }
+}
+
+impl Rules {
+    // Why a width by height board can't be played by these rules, if it
+    // can't: diagonals need a square board, and a free centre square an
+    // odd number of rows and columns.
+    pub fn check(&self, width: usize, height: usize) -> StdResult<(), Error> {
+        let diagonal = self
+            .patterns
+            .iter()
+            .any(|p| matches!(p, Pattern::Diagonal | Pattern::X));
+        if diagonal && width != height {
+            return Err(Error::invalid(format!(
+                "a {} by {} board has no diagonals to win on",
+                width, height
+            )));
+        }
+
+        if self.free_centre && (width % 2 == 0 || height % 2 == 0) {
+            return Err(Error::invalid(format!(
+                "a {} by {} board has no centre square to give away",
+                width, height
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn wins(&self, marked: &Grid<bool>) -> bool {
+        self.patterns.iter().any(|p| p.wins(marked))
+    }
+}
+
+#[derive(Clone)]
+pub struct Board {
+    numbers: Grid<u8>,
+    marked: Grid<bool>,
+    pub bingo: bool,
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    NoMark,
+    Marked,
+    Bingo,
+}
+
+impl Board {
+    // A board of rows, all as long as each other, to be played by rules.
+    pub fn new(rows: Vec<Vec<u8>>, rules: &Rules) -> StdResult<Self, Error> {
+        if rows.is_empty() || rows[0].is_empty() {
+            return Err(Error::invalid("a board needs at least one number"));
+        }
+
+        let numbers = Grid::from_rows(rows)?;
+        let (width, height) = (numbers.width(), numbers.height());
+        rules.check(width, height)?;
+
+        let mut marked = Grid::new(width, height, false);
+        if rules.free_centre {
+            if let Some(centre) = marked.get_mut(width / 2, height / 2) {
+                *centre = true;
+            }
+        }
+
+        Ok(Board {
+            numbers,
+            marked,
+            bingo: false,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.numbers.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.numbers.height()
+    }
+
+    pub fn play(&mut self, drawn: u8, rules: &Rules) -> Outcome {
+        let mut outcome = Outcome::NoMark;
+        for ((x, y), number) in self.numbers.iter() {
+            if *number == drawn {
+                if let Some(mark) = self.marked.get_mut(x, y) {
+                    *mark = true;
+                }
+                outcome = Outcome::Marked;
+            }
+        }
+
+        if matches!(outcome, Outcome::Marked) && rules.wins(&self.marked) {
+            outcome = Outcome::Bingo;
+            self.bingo = true;
+        }
+
+        outcome
+    }
+
+    pub fn score(&self, last_drawn: u8) -> usize {
+        if !self.bingo {
+            return 0;
+        }
+
+        let unmarked: usize = self
+            .numbers
+            .iter()
+            .filter(|((x, y), _)| !self.is_marked(*x, *y))
+            .map(|(_, number)| *number as usize)
+            .sum();
+
+        unmarked * last_drawn as usize
+    }
+
+    fn is_marked(&self, x: usize, y: usize) -> bool {
+        self.marked.get(x, y) == Some(&true)
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut display = String::new();
+        writeln!(display)?;
+        for (y, row) in self.numbers.rows().enumerate() {
+            for (x, number) in row.iter().enumerate() {
+                if self.is_marked(x, y) {
+                    write!(display, "{:3} ", "X ")?;
+                } else {
+                    write!(display, "{:<3} ", number)?;
+                }
+            }
+
+            display.pop();
+            display.push('\n');
+        }
+
+        f.write_str(&display)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A board's marks from rows of '#' (marked) and '.'.
+    fn marks(rows: &[&str]) -> Grid<bool> {
+        Grid::from_rows(
+            rows.iter()
+                .map(|r| r.chars().map(|c| c == '#').collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn patterns_win_on_their_shapes() {
+        let x = marks(&["#.#", ".#.", "#.#"]);
+        assert!(Pattern::X.wins(&x));
+        assert!(Pattern::Diagonal.wins(&x));
+        assert!(Pattern::FourCorners.wins(&x));
+        assert!(!Pattern::Row.wins(&x));
+        assert!(!Pattern::Blackout.wins(&x));
+
+        let up = marks(&["..#", ".#.", "#.."]);
+        assert!(Pattern::Diagonal.wins(&up));
+        assert!(!Pattern::X.wins(&up));
+        assert!(!Pattern::FourCorners.wins(&up));
+
+        assert!(Pattern::Column.wins(&marks(&[".#.", ".#.", ".#."])));
+        assert!(Pattern::Blackout.wins(&marks(&["###", "###", "###"])));
+
+        let middle_row = Pattern::Custom(|m| m.row(1).is_some_and(|r| r.iter().all(|c| *c)));
+        assert!(middle_row.wins(&marks(&["...", "###", "..."])));
+        assert!(!middle_row.wins(&marks(&["###", "...", "..."])));
+    }
+
+    #[test]
+    fn plays_any_shape_of_board() {
+        let rules = Rules::default();
+        let mut board = Board::new(vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]], &rules).unwrap();
+        assert_eq!((board.width(), board.height()), (4, 2));
+
+        assert!(matches!(board.play(9, &rules), Outcome::NoMark));
+        assert!(matches!(board.play(3, &rules), Outcome::Marked));
+        assert!(matches!(board.play(7, &rules), Outcome::Bingo));
+        assert_eq!(board.score(7), (1 + 2 + 4 + 5 + 6 + 8) * 7);
+
+        assert!(Board::new(vec![vec![1, 2], vec![3]], &rules).is_err());
+    }
+
+    #[test]
+    fn free_centre_starts_marked() {
+        let rules = Rules {
+            patterns: vec![Pattern::X],
+            free_centre: true,
+        };
+        let rows = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
+        let mut board = Board::new(rows.clone(), &rules).unwrap();
+        for n in [1, 3, 7].iter() {
+            assert!(matches!(board.play(*n, &rules), Outcome::Marked));
+        }
+        assert!(matches!(board.play(9, &rules), Outcome::Bingo));
+        assert_eq!(board.score(9), (2 + 4 + 6 + 8) * 9);
+
+        let square = vec![vec![1, 2], vec![3, 4]];
+        assert!(Board::new(square, &rules).is_err());
+        assert!(Board::new(vec![vec![1, 2, 3]], &rules).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+pub mod bingo;
+
+use crate::{Error, Expect, Input, ParseError, Solution};
+use bingo::Rules;
+
+pub struct Game {
+    draws: Vec<u8>,
+    boards: Vec<bingo::Board>,
+}
+
+impl Game {
+    // Plays every draw against every board, returning the boards in the
+    // order they won along with the number drawn that won each of them.
+    fn winners(&self, rules: &Rules) -> Vec<(bingo::Board, u8)> {
+        let mut boards = self.boards.clone();
+        let mut winners: Vec<(bingo::Board, u8)> = vec![];
+        for drawn in &self.draws {
+            for board in boards.iter_mut() {
+                if let bingo::Outcome::Bingo = board.play(*drawn, rules) {
+                    winners.push((board.clone(), *drawn))
+                }
+            }
+
+            boards.retain(|b| !b.bingo);
+        }
+
+        winners
+    }
+}
+
+// Boards take whatever shape the input gives them, and are played by
+// rules, plain bingo unless set otherwise.
+#[derive(Default)]
+pub struct Day4 {
+    pub rules: Rules,
+}
+
+impl Solution for Day4 {
+    type Input = Game;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn expect(&self) -> Expect {
+        Expect::new().non_empty().charset("0123456789, ")
+    }
+
+    fn parse(&self, input: &Input) -> Result<Self::Input, Error> {
+        let blocks = crate::blocks(input);
+        let mut blocks = blocks.iter();
+
+        let draws: Vec<u8> = match blocks.next().map(|b| b.as_slice()) {
+            Some([(n, line)]) => crate::parse_csv_line(*n, line)?,
+            _ => {
+                return Err(ParseError::new(
+                    1,
+                    1,
+                    "expected a line of comma separated numbers drawn for bingo",
+                )
+                .into())
+            }
+        };
+
+        let mut boards: Vec<bingo::Board> = vec![];
+        for block in blocks {
+            let mut rows: Vec<Vec<u8>> = vec![];
+            for (n, line) in block {
+                let mut row = vec![];
+                for s in line.split_ascii_whitespace() {
+                    let number = s.parse::<u8>().map_err(|_| {
+                        ParseError::at(*n, line, s, format!("invalid board number '{}'", s))
+                    })?;
+                    row.push(number);
+                }
+
+                if let Some(first) = rows.first() {
+                    if row.len() != first.len() {
+                        return Err(ParseError::new(
+                            *n,
+                            1,
+                            format!(
+                                "expected {} numbers like line {}, got {}",
+                                first.len(),
+                                block[0].0,
+                                row.len()
+                            ),
+                        )
+                        .into());
+                    }
+                }
+                rows.push(row);
+            }
+
+            let board = bingo::Board::new(rows, &self.rules)
+                .map_err(|why| ParseError::new(block[0].0, 1, why.message()))?;
+            boards.push(board);
+        }
+
+        if boards.is_empty() {
+            return Err(
+                ParseError::new(2, 1, "expected one or more deserialized bingo boards").into(),
+            );
+        }
+
+        Ok(Game { draws, boards })
+    }
+
+    fn part1(&self, game: &Self::Input) -> Result<Self::Answer1, Error> {
+        match game.winners(&self.rules).first() {
+            Some((first, drawn)) => Ok(first.score(*drawn)),
+            None => Err(Error::unsolvable("no board won")),
+        }
+    }
+
+    fn part2(&self, game: &Self::Input) -> Result<Self::Answer2, Error> {
+        match game.winners(&self.rules).last() {
+            Some((last, drawn)) => Ok(last.score(*drawn)),
+            None => Err(Error::unsolvable("no board won")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bingo::Pattern;
+
+    #[test]
+    fn boards_take_the_input_shape() {
+        let input = Input::new(
+            "small.txt",
+            "5,6,1,9,3\n\n1 2 3\n4 5 6\n7 8 9\n\n9 8\n7 6\n".to_string(),
+        );
+        let day = Day4::default();
+        let game = day.parse(&input).unwrap();
+        assert_eq!(game.boards[0].width(), 3);
+        assert_eq!(game.boards[1].height(), 2);
+        // Plain bingo waits for 3, to finish the first board's last column.
+        assert_eq!(day.part1(&game).unwrap(), (2 + 4 + 7 + 8) * 3);
+
+        let x = Day4 {
+            rules: Rules {
+                patterns: vec![Pattern::X],
+                free_centre: true,
+            },
+        };
+        assert_eq!(
+            x.parse(&input).err().unwrap().to_string(),
+            "failed to parse input: 7:1: a 2 by 2 board has no centre square to give away"
+        );
+
+        let ragged = Input::new("ragged.txt", "1,2\n\n1 2 3\n4 5\n".to_string());
+        assert_eq!(
+            day.parse(&ragged).err().unwrap().to_string(),
+            "failed to parse input: 4:1: expected 3 numbers like line 3, got 2"
+        );
+
+        let diagonal = Day4 {
+            rules: Rules {
+                patterns: vec![Pattern::Diagonal],
+                free_centre: false,
+            },
+        };
+        let game = diagonal.parse(&input).unwrap();
+        // Drawing 9 completes a diagonal on both boards, the first one
+        // listed winning first.
+        assert_eq!(diagonal.part1(&game).unwrap(), (2 + 3 + 4 + 7 + 8) * 9);
+        assert_eq!(diagonal.part2(&game).unwrap(), (8 + 7) * 9);
+    }
+}
@@ -0,0 +1,122 @@
+use super::grid::{Line, Slope};
+use crate::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Which way the lines to keep may run.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Orientation {
+    Any,
+    // Horizontal or vertical.
+    Axis,
+    // At 45 degrees either way.
+    Diagonal,
+}
+
+impl Orientation {
+    pub fn allows(self, slope: Slope) -> bool {
+        match self {
+            Orientation::Any => true,
+            Orientation::Axis => matches!(slope, Slope::Horizontal | Slope::Vertical),
+            Orientation::Diagonal => matches!(slope, Slope::DiagonalPos | Slope::DiagonalNeg),
+        }
+    }
+}
+
+// Picks out which vent lines to count, written as comma separated terms:
+// one of any, axis or diagonal, and min=<n> to skip lines covering fewer
+// than n points, e.g. "diagonal,min=3".
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct LineFilter {
+    pub orientation: Orientation,
+    pub min_points: usize,
+}
+
+impl Default for LineFilter {
+    fn default() -> LineFilter {
+        LineFilter {
+            orientation: Orientation::Any,
+            min_points: 1,
+        }
+    }
+}
+
+impl LineFilter {
+    pub fn allows(&self, l: &Line) -> bool {
+        self.orientation.allows(l.slope()) && l.point_count() >= self.min_points
+    }
+}
+
+impl FromStr for LineFilter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = LineFilter::default();
+        let mut oriented = false;
+        for term in s.split(',').map(str::trim) {
+            let orientation = match term {
+                "any" => Orientation::Any,
+                "axis" => Orientation::Axis,
+                "diagonal" => Orientation::Diagonal,
+                _ => {
+                    let n = term
+                        .strip_prefix("min=")
+                        .ok_or_else(|| Error::invalid(format!("unknown line filter '{}'", term)))?;
+                    filter.min_points = n
+                        .parse()
+                        .map_err(|_| Error::invalid(format!("invalid minimum '{}'", n)))?;
+                    continue;
+                }
+            };
+
+            if oriented {
+                return Err(Error::invalid(
+                    "expected one of any, axis or diagonal, not several",
+                ));
+            }
+            filter.orientation = orientation;
+            oriented = true;
+        }
+
+        Ok(filter)
+    }
+}
+
+impl Display for LineFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let orientation = match self.orientation {
+            Orientation::Any => "any",
+            Orientation::Axis => "axis",
+            Orientation::Diagonal => "diagonal",
+        };
+        write!(f, "{},min={}", orientation, self.min_points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day05::grid::Point;
+
+    #[test]
+    fn parses_and_applies_terms() {
+        let filter: LineFilter = "diagonal, min=3".parse().unwrap();
+        assert_eq!(filter.orientation, Orientation::Diagonal);
+        assert_eq!(filter.min_points, 3);
+        assert_eq!(filter.to_string(), "diagonal,min=3");
+
+        let line = |x2, y2| Line::new(Point { x: 0, y: 0 }, Point { x: x2, y: y2 });
+        assert!(filter.allows(&line(2, -2)));
+        assert!(!filter.allows(&line(1, 1)));
+        assert!(!filter.allows(&line(0, 5)));
+        assert!(!filter.allows(&line(4, 2)));
+
+        assert_eq!(
+            "min=2".parse::<LineFilter>().unwrap().orientation,
+            Orientation::Any
+        );
+        assert!("axis,diagonal".parse::<LineFilter>().is_err());
+        assert!("steep".parse::<LineFilter>().is_err());
+        assert!("min=x".parse::<LineFilter>().is_err());
+    }
+}
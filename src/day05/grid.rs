@@ -0,0 +1,618 @@
+use crate::grid::{Bounds, Cells, Grid, SparseGrid};
+use crate::Error;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl FromStr for Point {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mut x_str, mut y_str) = ("", "");
+
+        for (n, coord) in s.split(',').enumerate() {
+            match n {
+                0 => x_str = coord,
+                1 => y_str = coord,
+                _ => {
+                    return Err(Error::invalid(
+                        "got more than comma-delimited pair of coordinate strings",
+                    ))
+                }
+            }
+        }
+
+        let x = x_str.parse::<isize>();
+        let y = y_str.parse::<isize>();
+        if x.is_err() || y.is_err() {
+            return Err(Error::invalid("comma-delimited strings not numbers"));
+        }
+
+        Ok(Point {
+            x: x.unwrap(),
+            y: y.unwrap(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Line {
+    start: Point,
+    end: Point,
+    slope: Slope,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Slope {
+    // Single point lines count as vertical.
+    Vertical,
+    Horizontal,
+    DiagonalPos,
+    DiagonalNeg,
+    // Any other slope, as rise over run in lowest terms. The run is
+    // always positive.
+    Other { rise: isize, run: isize },
+}
+
+impl Line {
+    pub fn new(start: Point, end: Point) -> Line {
+        // If we're dealing with a left-running line or down-running
+        // vertical line, normalize it in the opposite direction for
+        // convenience of plotting points along it.
+        let (mut _start, mut _end) = (start, end);
+        if start.x > end.x || (start.x == end.x && start.y > end.y) {
+            _start = end;
+            _end = start;
+        }
+
+        let (run, rise) = (_end.x - _start.x, _end.y - _start.y);
+        let slope = if run == 0 {
+            Slope::Vertical
+        } else if rise == 0 {
+            Slope::Horizontal
+        } else if rise == run {
+            Slope::DiagonalPos
+        } else if rise == -run {
+            Slope::DiagonalNeg
+        } else {
+            let g = gcd(rise, run);
+            Slope::Other {
+                rise: rise / g,
+                run: run / g,
+            }
+        };
+
+        Line {
+            start: _start,
+            end: _end,
+            slope,
+        }
+    }
+
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    pub fn end(&self) -> Point {
+        self.end
+    }
+
+    pub fn slope(&self) -> Slope {
+        self.slope
+    }
+
+    // The smallest (x, y) move from one point of the line to the next.
+    pub fn step(&self) -> (isize, isize) {
+        match self.slope {
+            Slope::Vertical => (0, 1),
+            Slope::Horizontal => (1, 0),
+            Slope::DiagonalPos => (1, 1),
+            Slope::DiagonalNeg => (1, -1),
+            Slope::Other { rise, run } => (run, rise),
+        }
+    }
+
+    // How many whole-numbered points the line passes through, ends
+    // included.
+    pub fn point_count(&self) -> usize {
+        let (run, rise) = (self.end.x - self.start.x, self.end.y - self.start.y);
+        gcd(rise, run) as usize + 1
+    }
+
+    // The whole-numbered points exactly on the line, from start to end.
+    // A line like (0, 0) -> (4, 2) only has three: it steps over (1, 0.5)
+    // and (3, 1.5).
+    pub fn points(&self) -> Vec<Point> {
+        let (dx, dy) = self.step();
+        let Point { x, y } = self.start;
+        (0..self.point_count() as isize)
+            .map(|k| Point {
+                x: x + k * dx,
+                y: y + k * dy,
+            })
+            .collect()
+    }
+
+    // Whether p is one of the line's points.
+    pub fn contains(&self, p: Point) -> bool {
+        let (dx, dy) = self.step();
+        let (px, py) = (p.x - self.start.x, p.y - self.start.y);
+        let (low_y, high_y) = (self.start.y.min(self.end.y), self.start.y.max(self.end.y));
+
+        // Square to the step means off the line; the step being the
+        // smallest whole-numbered move, any whole-numbered point on the
+        // line is one of its points.
+        px * dy == py * dx
+            && (self.start.x..=self.end.x).contains(&p.x)
+            && (low_y..=high_y).contains(&p.y)
+    }
+
+    // The points to colour to draw the line, one per column or row,
+    // whichever the line covers more of, picked with Bresenham's
+    // algorithm. Unlike points() these only approximate the line when
+    // it isn't straight or at 45 degrees.
+    pub fn raster(&self) -> Vec<Point> {
+        let Point { x: x1, y: y1 } = self.end;
+        let Point { mut x, mut y } = self.start;
+        let (dx, dy) = ((x1 - x).abs(), -(y1 - y).abs());
+        let (sx, sy) = ((x1 - x).signum(), (y1 - y).signum());
+
+        let mut points = vec![];
+        let mut err = dx + dy;
+        loop {
+            points.push(Point { x, y });
+            if x == x1 && y == y1 {
+                return points;
+            }
+
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+}
+
+fn gcd(a: isize, b: isize) -> isize {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+// Counts of how many lines cover each point within bounds, kept on
+// whichever grid backend suits the input. The lines added are only kept
+// too once recording, as solving has no use for them.
+pub struct Field<G> {
+    cells: G,
+    bounds: Bounds,
+    lines: Option<Vec<Line>>,
+}
+
+impl Field<Grid<usize>> {
+    pub fn dense(bounds: Bounds) -> Field<Grid<usize>> {
+        Field {
+            cells: Grid::new(bounds.width(), bounds.height(), 0),
+            bounds,
+            lines: None,
+        }
+    }
+}
+
+impl Field<SparseGrid<usize>> {
+    pub fn sparse(bounds: Bounds) -> Field<SparseGrid<usize>> {
+        Field {
+            cells: SparseGrid::new(bounds.width(), bounds.height(), 0),
+            bounds,
+            lines: None,
+        }
+    }
+}
+
+impl<G: Cells<usize>> Field<G> {
+    // Keeps every line added from now on, to ask which pass through a
+    // point.
+    pub fn recording(mut self) -> Self {
+        self.lines.get_or_insert_with(Vec::new);
+        self
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    // The grid underneath, its (0, 0) being the bounds' corner.
+    pub fn cells(&self) -> &G {
+        &self.cells
+    }
+
+    pub fn add(&mut self, l: &Line) -> Result<(), Error> {
+        let (start, end) = (l.start, l.end);
+        if !self.bounds.contains(start.x, start.y) || !self.bounds.contains(end.x, end.y) {
+            return Err(Error::invalid(format!(
+                "line {:?} falls outside grid bounds {}",
+                l, self.bounds
+            )));
+        }
+
+        for p in l.points() {
+            if let Some(count) = self
+                .bounds
+                .offset(p.x, p.y)
+                .and_then(|(x, y)| self.cells.get_mut(x, y))
+            {
+                *count += 1;
+            }
+        }
+
+        if let Some(lines) = &mut self.lines {
+            lines.push(l.clone());
+        }
+        Ok(())
+    }
+
+    // How many lines cover p, or None if it's out of bounds.
+    pub fn count(&self, p: Point) -> Option<usize> {
+        let (x, y) = self.bounds.offset(p.x, p.y)?;
+        self.cells.get(x, y).copied()
+    }
+
+    pub fn intersections(&self) -> usize {
+        self.cells
+            .occupied()
+            .filter(|(_, count)| **count > 1)
+            .count()
+    }
+
+    // How many points are covered by each number of lines, 0 included
+    // unless there are more points in bounds than a usize can count.
+    pub fn histogram(&self) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for (_, count) in self.covered() {
+            *histogram.entry(count).or_insert(0) += 1;
+        }
+
+        let covered: usize = histogram.values().sum();
+        let uncovered = self
+            .bounds
+            .width()
+            .checked_mul(self.bounds.height())
+            .map(|area| area.saturating_sub(covered));
+        if let Some(uncovered) = uncovered.filter(|n| *n > 0) {
+            histogram.insert(0, uncovered);
+        }
+
+        histogram
+    }
+
+    // The points covered by threshold lines or more, row by row. Only
+    // ever covered points, so a threshold of 0 acts like 1.
+    pub fn at_least(&self, threshold: usize) -> Vec<Point> {
+        let mut points: Vec<Point> = self
+            .covered()
+            .filter(|(_, count)| *count >= threshold)
+            .map(|(p, _)| p)
+            .collect();
+        points.sort_by_key(|p| (p.y, p.x));
+        points
+    }
+
+    // The most lines covering any one point, and every point they cover
+    // together, or None if there are no lines.
+    pub fn hottest(&self) -> Option<(usize, Vec<Point>)> {
+        let most = self.covered().map(|(_, count)| count).max()?;
+        Some((most, self.at_least(most)))
+    }
+
+    // The lines added that pass through p, or None unless recording.
+    pub fn through(&self, p: Point) -> Option<Vec<&Line>> {
+        let lines = self.lines.as_ref()?;
+        Some(lines.iter().filter(|l| l.contains(p)).collect())
+    }
+
+    // Every point covered by a line and how many cover it.
+    fn covered(&self) -> impl Iterator<Item = (Point, usize)> + '_ {
+        self.cells
+            .occupied()
+            .filter(|(_, count)| **count > 0)
+            .map(move |((x, y), count)| {
+                let (x, y) = self.bounds.point(x, y);
+                (Point { x, y }, *count)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn horizontal_works() {
+        let (start, end) = (Point { x: 2, y: 3 }, Point { x: 7, y: 3 });
+        let line = Line::new(start, end);
+        assert_eq!(line.start, start);
+        assert_eq!(line.end, end);
+        assert_eq!(line.slope, Slope::Horizontal);
+        assert_eq!(
+            line.points(),
+            vec![
+                Point { x: 2, y: 3 },
+                Point { x: 3, y: 3 },
+                Point { x: 4, y: 3 },
+                Point { x: 5, y: 3 },
+                Point { x: 6, y: 3 },
+                Point { x: 7, y: 3 },
+            ]
+        )
+    }
+
+    #[test]
+    fn point_swap_works() {
+        let (start, end) = (Point { x: 2, y: 3 }, Point { x: 7, y: 3 });
+        let line = Line::new(end, start); // Flipped.
+        assert_eq!(line.start, start);
+        assert_eq!(line.end, end);
+        assert_eq!(line.slope, Slope::Horizontal);
+        assert_eq!(
+            line.points(),
+            vec![
+                Point { x: 2, y: 3 },
+                Point { x: 3, y: 3 },
+                Point { x: 4, y: 3 },
+                Point { x: 5, y: 3 },
+                Point { x: 6, y: 3 },
+                Point { x: 7, y: 3 },
+            ]
+        )
+    }
+
+    #[test]
+    fn vertical_works() {
+        let (start, end) = (Point { x: 2, y: 3 }, Point { x: 2, y: 10 });
+        let line = Line::new(start, end);
+        assert_eq!(line.start, start);
+        assert_eq!(line.end, end);
+        assert_eq!(line.slope, Slope::Vertical);
+        assert_eq!(
+            line.points(),
+            vec![
+                Point { x: 2, y: 3 },
+                Point { x: 2, y: 4 },
+                Point { x: 2, y: 5 },
+                Point { x: 2, y: 6 },
+                Point { x: 2, y: 7 },
+                Point { x: 2, y: 8 },
+                Point { x: 2, y: 9 },
+                Point { x: 2, y: 10 },
+            ]
+        )
+    }
+
+    #[test]
+    fn diagonal_pos_works() {
+        let (start, end) = (Point { x: 2, y: 3 }, Point { x: 6, y: 7 });
+        let line = Line::new(start, end);
+        assert_eq!(line.start, start);
+        assert_eq!(line.end, end);
+        assert_eq!(line.slope, Slope::DiagonalPos);
+        assert_eq!(
+            line.points(),
+            vec![
+                Point { x: 2, y: 3 },
+                Point { x: 3, y: 4 },
+                Point { x: 4, y: 5 },
+                Point { x: 5, y: 6 },
+                Point { x: 6, y: 7 },
+            ]
+        )
+    }
+
+    #[test]
+    fn diagonal_neg_works() {
+        let (start, end) = (Point { x: 8, y: 10 }, Point { x: 10, y: 8 });
+        let line = Line::new(start, end);
+        assert_eq!(line.start, start);
+        assert_eq!(line.end, end);
+        assert_eq!(line.slope, Slope::DiagonalNeg);
+        assert_eq!(
+            line.points(),
+            vec![
+                Point { x: 8, y: 10 },
+                Point { x: 9, y: 9 },
+                Point { x: 10, y: 8 },
+            ]
+        )
+    }
+
+    #[test]
+    fn shallow_works() {
+        let line = Line::new(Point { x: 6, y: 4 }, Point { x: 0, y: 1 });
+        assert_eq!(line.start, Point { x: 0, y: 1 });
+        assert_eq!(line.slope, Slope::Other { rise: 1, run: 2 });
+        assert_eq!(
+            line.points(),
+            vec![
+                Point { x: 0, y: 1 },
+                Point { x: 2, y: 2 },
+                Point { x: 4, y: 3 },
+                Point { x: 6, y: 4 },
+            ]
+        );
+        assert_eq!(line.raster().len(), 7);
+    }
+
+    #[test]
+    fn steep_works() {
+        // Truncating division once called this a diagonal.
+        let line = Line::new(Point { x: 0, y: 0 }, Point { x: 2, y: 3 });
+        assert_eq!(line.slope, Slope::Other { rise: 3, run: 2 });
+        assert_eq!(
+            line.points(),
+            vec![Point { x: 0, y: 0 }, Point { x: 2, y: 3 }]
+        );
+        assert_eq!(
+            line.raster(),
+            vec![
+                Point { x: 0, y: 0 },
+                Point { x: 1, y: 1 },
+                Point { x: 1, y: 2 },
+                Point { x: 2, y: 3 },
+            ]
+        );
+
+        let down = Line::new(Point { x: 1, y: 9 }, Point { x: 3, y: 1 });
+        assert_eq!(down.slope, Slope::Other { rise: -4, run: 1 });
+        assert_eq!(
+            down.points(),
+            vec![
+                Point { x: 1, y: 9 },
+                Point { x: 2, y: 5 },
+                Point { x: 3, y: 1 },
+            ]
+        );
+        assert_eq!(down.raster().len(), 9);
+    }
+
+    #[test]
+    fn single_point_works() {
+        let p = Point { x: 4, y: 4 };
+        let line = Line::new(p, p);
+        assert_eq!(line.slope, Slope::Vertical);
+        assert_eq!(line.point_count(), 1);
+        assert_eq!(line.points(), vec![p]);
+        assert_eq!(line.raster(), vec![p]);
+    }
+
+    #[test]
+    fn raster_matches_points_at_45_degrees() {
+        let line = Line::new(Point { x: 8, y: 10 }, Point { x: 10, y: 8 });
+        assert_eq!(line.raster(), line.points());
+    }
+
+    #[test]
+    fn intersections_works() {
+        /*
+        x x x X X
+        . . . X .
+        . x X X .
+        . x . x .
+        x . . x .
+        */
+
+        let l1 = Line::new(Point { x: 0, y: 0 }, Point { x: 4, y: 4 });
+        let l2 = Line::new(Point { x: 1, y: 2 }, Point { x: 3, y: 2 });
+        let l3 = Line::new(Point { x: 3, y: 4 }, Point { x: 3, y: 0 });
+        let l4 = Line::new(Point { x: 0, y: 4 }, Point { x: 4, y: 4 });
+
+        let bounds = Bounds::around(vec![(0, 0), (4, 4)]).unwrap();
+        let mut grid = Field::dense(bounds);
+        let mut sparse = Field::sparse(bounds);
+        for l in [&l1, &l2, &l3, &l4].iter() {
+            grid.add(l).unwrap();
+            sparse.add(l).unwrap();
+        }
+
+        println!("{}", grid.cells());
+
+        assert_eq!(grid.intersections(), 5);
+        assert_eq!(sparse.intersections(), 5);
+        assert_eq!(grid.count(Point { x: 3, y: 4 }), Some(2));
+    }
+
+    #[test]
+    fn sparse_handles_huge_coordinates() {
+        let l1 = Line::new(Point { x: 2_000_000, y: 0 }, Point { x: 2_000_000, y: 9 });
+        let l2 = Line::new(Point { x: 1_999_995, y: 5 }, Point { x: 2_000_004, y: 5 });
+        assert_eq!(l1.point_count(), 10);
+
+        let bounds = Bounds::around(vec![(0, 0), (2_999_999, 2_999_999)]).unwrap();
+        let mut grid = Field::sparse(bounds);
+        grid.add(&l1).unwrap();
+        grid.add(&l2).unwrap();
+        assert_eq!(grid.intersections(), 1);
+        assert_eq!(grid.cells().len(), 19);
+
+        let off = Line::new(Point { x: 3_000_000, y: 0 }, Point { x: 3_000_000, y: 1 });
+        assert!(grid.add(&off).is_err());
+        assert_eq!(grid.histogram().get(&0), Some(&(9_000_000_000_000 - 19)));
+
+        // Too many points to count the uncovered ones.
+        let far = 1 << 40;
+        let mut huge = Field::sparse(Bounds::around(vec![(-far, -far), (far, far)]).unwrap());
+        huge.add(&l1).unwrap();
+        assert_eq!(
+            huge.histogram().into_iter().collect::<Vec<_>>(),
+            vec![(1, 10)]
+        );
+    }
+
+    #[test]
+    fn negative_coordinates_work() {
+        // Runs down through y = 0 and out the other side.
+        let l1 = Line::new(Point { x: -2, y: 2 }, Point { x: 2, y: -2 });
+        let l2 = Line::new(Point { x: -3, y: 0 }, Point { x: 3, y: 0 });
+        assert_eq!(l1.points().len(), 5);
+
+        let bounds = Bounds::around(vec![(-3, -2), (3, 2)]).unwrap();
+        let mut grid = Field::dense(bounds);
+        grid.add(&l1).unwrap();
+        grid.add(&l2).unwrap();
+        assert_eq!(grid.cells().width(), 7);
+        assert_eq!(grid.count(Point { x: 0, y: 0 }), Some(2));
+        assert_eq!(grid.count(Point { x: -4, y: 0 }), None);
+        assert_eq!(grid.intersections(), 1);
+    }
+
+    #[test]
+    fn contains_only_its_own_points() {
+        let line = Line::new(Point { x: 6, y: -1 }, Point { x: 0, y: 2 });
+        for p in line.points() {
+            assert!(line.contains(p));
+        }
+        assert!(!line.contains(Point { x: 1, y: 1 }));
+        assert!(!line.contains(Point { x: 8, y: -2 }));
+    }
+
+    #[test]
+    fn answers_questions_about_the_field() {
+        let l1 = Line::new(Point { x: 0, y: 0 }, Point { x: 2, y: 0 });
+        let l2 = Line::new(Point { x: 1, y: -1 }, Point { x: 1, y: 1 });
+        let l3 = Line::new(Point { x: 0, y: -1 }, Point { x: 2, y: 1 });
+
+        let bounds = Bounds::around(vec![(0, -1), (2, 1)]).unwrap();
+        let mut field = Field::sparse(bounds).recording();
+        for l in [&l1, &l2, &l3].iter() {
+            field.add(l).unwrap();
+        }
+
+        let histogram: Vec<_> = field.histogram().into_iter().collect();
+        assert_eq!(histogram, vec![(0, 2), (1, 6), (3, 1)]);
+
+        assert_eq!(field.at_least(3), vec![Point { x: 1, y: 0 }]);
+        assert_eq!(field.at_least(0).len(), 7);
+        assert_eq!(field.hottest(), Some((3, vec![Point { x: 1, y: 0 }])));
+        assert_eq!(field.through(Point { x: 1, y: 1 }), Some(vec![&l2]));
+        assert_eq!(
+            field.through(Point { x: 1, y: 0 }).map(|l| l.len()),
+            Some(3)
+        );
+        assert!(Field::dense(bounds).hottest().is_none());
+
+        let mut unrecorded = Field::dense(bounds);
+        unrecorded.add(&l1).unwrap();
+        assert!(unrecorded.through(Point { x: 1, y: 0 }).is_none());
+    }
+}
@@ -0,0 +1,284 @@
+pub mod filter;
+pub mod grid;
+pub mod overlap;
+
+pub use filter::{LineFilter, Orientation};
+
+use crate::grid::{Bounds, Cells};
+use crate::image::{self, Ramp, Rgb, Svg};
+use crate::{Error, Expect, Input, ParseError, Solution};
+use grid::{Field, Line, Slope};
+use std::str::FromStr;
+
+// A dense grid is used unless it would have more than this many cells for
+// every point the lines cover, past which most of it would sit empty and
+// a sparse grid is cheaper.
+const MAX_CELLS_PER_POINT: usize = 64;
+
+// The most pixels a heatmap may have, one per point in bounds.
+const MAX_PIXELS: usize = 1 << 24;
+
+pub struct Vents {
+    lines: Vec<Line>,
+    bounds: Bounds,
+}
+
+impl Vents {
+    pub fn lines(&self) -> &[Line] {
+        &self.lines
+    }
+
+    // The smallest rectangle holding every line.
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+}
+
+// How overlaps get counted: by drawing every line onto a grid, or from
+// the lines pairwise, which never needs more memory than the lines
+// themselves however far apart they are.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub enum Counting {
+    #[default]
+    Grid,
+    Pairwise,
+}
+
+impl FromStr for Counting {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "grid" => Ok(Counting::Grid),
+            "pairwise" => Ok(Counting::Pairwise),
+            _ => Err(Error::invalid(format!(
+                "expected grid or pairwise, got '{}'",
+                s
+            ))),
+        }
+    }
+}
+
+// Part 1 only counts horizontal and vertical lines, part 2 every line.
+// The filter narrows both further, to explore which lines make up the
+// overlaps; it lets every line through by default.
+#[derive(Default)]
+pub struct Day5 {
+    pub filter: LineFilter,
+    pub counting: Counting,
+}
+
+impl Solution for Day5 {
+    type Input = Vents;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn expect(&self) -> Expect {
+        Expect::new().non_empty().charset("0123456789,-> ")
+    }
+
+    fn parse(&self, input: &Input) -> Result<Self::Input, Error> {
+        let mut lines: Vec<grid::Line> = vec![];
+        for (n, line) in input.lines() {
+            let pairs: Vec<_> = line.split(" -> ").collect();
+            if pairs.len() != 2 {
+                return Err(ParseError::new(
+                    n,
+                    1,
+                    format!("expected 'x,y -> x,y', got '{}'", line),
+                )
+                .into());
+            }
+
+            let point = |s: &str| {
+                s.parse::<grid::Point>()
+                    .map_err(|why| ParseError::at(n, line, s, why.message()))
+            };
+            let (start, end) = (point(pairs[0])?, point(pairs[1])?);
+
+            lines.push(Line::new(start, end));
+        }
+
+        let ends = lines
+            .iter()
+            .flat_map(|l| vec![l.start(), l.end()])
+            .map(|p| (p.x, p.y));
+        let bounds = Bounds::around(ends).ok_or_else(|| Error::invalid("no vent lines"))?;
+
+        Ok(Vents { lines, bounds })
+    }
+
+    fn part1(&self, vents: &Self::Input) -> Result<Self::Answer1, Error> {
+        self.overlaps(vents, Orientation::Axis)
+    }
+
+    fn part2(&self, vents: &Self::Input) -> Result<Self::Answer2, Error> {
+        self.overlaps(vents, Orientation::Any)
+    }
+}
+
+impl Day5 {
+    // How many points at least two lines cover, counting only lines
+    // running orientation and let through by the filter.
+    fn overlaps(&self, vents: &Vents, orientation: Orientation) -> Result<usize, Error> {
+        let lines = self.select(vents, orientation);
+        if self.counting == Counting::Pairwise {
+            Ok(overlap::intersections(&lines))
+        } else if is_dense(vents.bounds, &lines) {
+            plot(Field::dense(vents.bounds), &lines)
+        } else {
+            plot(Field::sparse(vents.bounds), &lines)
+        }
+    }
+
+    // The lines the filter lets through, shaded by how many cover each
+    // point, as a PPM image.
+    pub fn heatmap(&self, vents: &Vents) -> Result<Vec<u8>, Error> {
+        let bounds = vents.bounds;
+        if bounds.width().saturating_mul(bounds.height()) > MAX_PIXELS {
+            return Err(Error::invalid(format!(
+                "{} by {} is too big for a heatmap",
+                bounds.width(),
+                bounds.height()
+            )));
+        }
+
+        let mut field = Field::dense(bounds);
+        for l in self.select(vents, Orientation::Any) {
+            field.add(l)?;
+        }
+
+        Ok(image::ppm(field.cells(), &Ramp::heat()))
+    }
+
+    // The lines the filter lets through as an SVG drawing, coloured by
+    // which way they run.
+    pub fn drawing(&self, vents: &Vents) -> Svg {
+        let mut svg = Svg::new(vents.bounds);
+        for l in self.select(vents, Orientation::Any) {
+            let colour = match l.slope() {
+                Slope::Horizontal | Slope::Vertical => Rgb(110, 190, 255),
+                Slope::DiagonalPos | Slope::DiagonalNeg => Rgb(255, 160, 0),
+                Slope::Other { .. } => Rgb(170, 255, 110),
+            };
+            let (start, end) = (l.start(), l.end());
+            svg.line((start.x, start.y), (end.x, end.y), colour);
+        }
+        svg
+    }
+
+    fn select<'a>(&self, vents: &'a Vents, orientation: Orientation) -> Vec<&'a Line> {
+        vents
+            .lines
+            .iter()
+            .filter(|l| orientation.allows(l.slope()) && self.filter.allows(l))
+            .collect()
+    }
+}
+
+fn is_dense(bounds: Bounds, lines: &[&Line]) -> bool {
+    let points: usize = lines.iter().map(|l| l.point_count()).sum();
+    bounds
+        .width()
+        .checked_mul(bounds.height())
+        .is_some_and(|cells| cells / MAX_CELLS_PER_POINT <= points)
+}
+
+fn plot<G: Cells<usize>>(mut grid: Field<G>, lines: &[&Line]) -> Result<usize, Error> {
+    for l in lines {
+        grid.add(l)?;
+    }
+
+    Ok(grid.intersections())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn far_apart_lines_use_a_sparse_grid() {
+        let input = Input::new(
+            "far.txt",
+            "1999999,5 -> 2000001,5\n2000000,4 -> 2000000,6\n0,0 -> 2,2\n".to_string(),
+        );
+        let vents = Day5::default().parse(&input).unwrap();
+        let lines: Vec<&Line> = vents.lines.iter().collect();
+        assert!(!is_dense(vents.bounds, &lines));
+        assert_eq!(Day5::default().part2(&vents).unwrap(), 1);
+    }
+
+    #[test]
+    fn counts_pairwise_when_asked() {
+        let pairwise = Day5 {
+            counting: "pairwise".parse().unwrap(),
+            ..Day5::default()
+        };
+        let failures = crate::check_examples(5, &pairwise);
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+        assert!("hash".parse::<Counting>().is_err());
+    }
+
+    #[test]
+    fn lines_anywhere_in_the_plane_work() {
+        let input = Input::new(
+            "signed.txt",
+            "-3,-3 -> 3,3\n-3,3 -> 3,-3\n-5,0 -> -1,0\n".to_string(),
+        );
+        let vents = Day5::default().parse(&input).unwrap();
+        assert_eq!(
+            vents.bounds,
+            Bounds::around(vec![(-5, -3), (3, 3)]).unwrap()
+        );
+        assert_eq!(Day5::default().part2(&vents).unwrap(), 1);
+    }
+
+    #[test]
+    fn draws_the_selected_lines() {
+        let input = Input::new(
+            "drawn.txt",
+            "0,0 -> 2,0\n1,-1 -> 1,1\n0,1 -> 1,1\n".to_string(),
+        );
+        let vents = Day5::default().parse(&input).unwrap();
+
+        let heatmap = Day5::default().heatmap(&vents).unwrap();
+        assert!(heatmap.starts_with(b"P6\n3 3\n255\n"));
+        // The middle point, covered twice, is the hottest: white.
+        assert_eq!(&heatmap[11 + 4 * 3..11 + 5 * 3], &[255, 255, 255]);
+
+        let day = Day5 {
+            filter: "min=3".parse().unwrap(),
+            ..Day5::default()
+        };
+        assert_eq!(day.drawing(&vents).to_string().matches("<line").count(), 2);
+    }
+
+    #[test]
+    fn reports_bad_points_once() {
+        let input = Input::new("bad.txt", "0,0 -> 1,1\n0,0 -> 1,x\n".to_string());
+        let why = Day5::default().parse(&input).err().unwrap();
+        assert_eq!(
+            why.to_string(),
+            "failed to parse input: 2:8: comma-delimited strings not numbers"
+        );
+    }
+
+    #[test]
+    fn filters_narrow_both_parts() {
+        let input = Input::new(
+            "filtered.txt",
+            "0,0 -> 4,0\n2,0 -> 2,1\n0,4 -> 4,0\n0,0 -> 4,4\n".to_string(),
+        );
+        let vents = Day5::default().parse(&input).unwrap();
+        assert_eq!(Day5::default().part1(&vents).unwrap(), 1);
+        assert_eq!(Day5::default().part2(&vents).unwrap(), 4);
+
+        let day = |spec: &str| Day5 {
+            filter: spec.parse().unwrap(),
+            ..Day5::default()
+        };
+        assert_eq!(day("diagonal").part1(&vents).unwrap(), 0);
+        assert_eq!(day("diagonal").part2(&vents).unwrap(), 1);
+        assert_eq!(day("min=3").part2(&vents).unwrap(), 3);
+    }
+}
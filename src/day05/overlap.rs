@@ -0,0 +1,164 @@
+use super::grid::{Line, Point, Slope};
+use std::collections::HashSet;
+
+// Counts the points where two or more lines overlap, the same number
+// Field::intersections gives, but worked out from the segments pairwise
+// rather than by drawing every line onto a grid. Crossing lines meet in
+// at most one point; only collinear lines that overlap share a run.
+pub fn intersections(lines: &[&Line]) -> usize {
+    let mut shared = HashSet::new();
+    for (i, a) in lines.iter().enumerate() {
+        for b in &lines[i + 1..] {
+            if a.slope() == b.slope() {
+                shared.extend(overlap(a, b));
+            } else if let Some(p) = crossing(a, b) {
+                shared.insert(p);
+            }
+        }
+    }
+
+    shared.len()
+}
+
+// The line as a x + b y = c, with (a, b) square to its step. Lines with
+// the same slope share (a, b), so they're collinear exactly when their c
+// matches too.
+fn equation(l: &Line) -> (isize, isize, isize) {
+    let (dx, dy) = l.step();
+    let Point { x, y } = l.start();
+    (dy, -dx, dy * x - dx * y)
+}
+
+// Where along its line a point sits: y for vertical lines, x for the
+// rest. Lines run from their lowest position to their highest.
+fn position(l: &Line, p: Point) -> isize {
+    match l.slope() {
+        Slope::Vertical => p.y,
+        _ => p.x,
+    }
+}
+
+// The points shared by two lines with the same slope. Collinear lines
+// pass through the same whole-numbered points, so stepping along one
+// from the start of the overlap finds every one.
+fn overlap(a: &Line, b: &Line) -> Vec<Point> {
+    if equation(a) != equation(b) {
+        return vec![];
+    }
+
+    let lo = position(a, a.start()).max(position(b, b.start()));
+    let hi = position(a, a.end()).min(position(b, b.end()));
+    if lo > hi {
+        return vec![];
+    }
+
+    let (dx, dy) = a.step();
+    let stride = if dx == 0 { dy } else { dx };
+    let from = position(a, a.start());
+    let Point { x, y } = a.start();
+    ((lo - from) / stride..=(hi - from) / stride)
+        .map(|k| Point {
+            x: x + k * dx,
+            y: y + k * dy,
+        })
+        .collect()
+}
+
+// The one point where two lines of different slopes cross, if they do so
+// on a whole-numbered point within both segments.
+fn crossing(a: &Line, b: &Line) -> Option<Point> {
+    let ((a1, b1, c1), (a2, b2, c2)) = (equation(a), equation(b));
+    let det = a1 * b2 - a2 * b1;
+    let (x, y) = (c1 * b2 - c2 * b1, a1 * c2 - a2 * c1);
+    if x % det != 0 || y % det != 0 {
+        return None;
+    }
+
+    let p = Point {
+        x: x / det,
+        y: y / det,
+    };
+    if covers(a, p) && covers(b, p) {
+        Some(p)
+    } else {
+        None
+    }
+}
+
+// Whether p, a whole-numbered point already known to be on l's infinite
+// line, is within the segment. Every such point is one of l's points,
+// as l steps by the smallest whole-numbered move along it.
+fn covers(l: &Line, p: Point) -> bool {
+    let t = position(l, p);
+    position(l, l.start()) <= t && t <= position(l, l.end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day05::grid::Field;
+    use crate::grid::Bounds;
+
+    fn line(x1: isize, y1: isize, x2: isize, y2: isize) -> Line {
+        Line::new(Point { x: x1, y: y1 }, Point { x: x2, y: y2 })
+    }
+
+    fn pairwise(lines: &[Line]) -> usize {
+        intersections(&lines.iter().collect::<Vec<_>>())
+    }
+
+    fn rasterized(lines: &[Line]) -> usize {
+        let ends = lines
+            .iter()
+            .flat_map(|l| vec![l.start(), l.end()])
+            .map(|p| (p.x, p.y));
+        let mut grid = Field::dense(Bounds::around(ends).unwrap());
+        for l in lines {
+            grid.add(l).unwrap();
+        }
+        grid.intersections()
+    }
+
+    #[test]
+    fn counts_crossings_and_collinear_runs() {
+        let lines = vec![
+            line(0, 0, 4, 4),
+            line(2, 2, 6, 6), // Overlaps the first from (2, 2) to (4, 4).
+            line(0, 4, 4, 0), // Crosses both at (2, 2).
+            line(0, 1, 3, 1), // Crosses the first at (1, 1) and the third at (3, 1).
+            line(1, 0, 2, 1), // Ends on the fourth, at (2, 1), and misses the third.
+        ];
+        assert_eq!(pairwise(&lines), 6);
+        assert_eq!(pairwise(&lines), rasterized(&lines));
+
+        // Diagonals of different parity never share a lattice point.
+        assert_eq!(pairwise(&[line(0, 0, 3, 3), line(0, 3, 3, 0)]), 0);
+    }
+
+    #[test]
+    fn agrees_with_grid() {
+        // A fixed pseudo-random field of lines of every slope, dense
+        // enough for plenty of crossings and collinear overlaps.
+        let mut seed: u64 = 0x5eed;
+        let mut next = |n: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % n) as isize
+        };
+
+        let mut lines = vec![];
+        for _ in 0..200 {
+            let (x, y, len) = (next(30) - 15, next(30) - 15, next(10));
+            lines.push(match next(5) {
+                0 => line(x, y, x, y + len),
+                1 => line(x, y, x + len, y),
+                2 => line(x, y, x + len, y + len),
+                3 => line(x, y + len, x + len, y),
+                _ => line(x, y, next(30) - 15, next(30) - 15),
+            });
+        }
+
+        assert_eq!(pairwise(&lines), rasterized(&lines));
+    }
+}
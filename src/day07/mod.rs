@@ -0,0 +1,138 @@
+use crate::{Error, Expect, Input, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn expect(&self) -> Expect {
+        Expect::new().csv_line().charset("0123456789,")
+    }
+
+    fn parse(&self, input: &Input) -> Result<Self::Input, Error> {
+        Ok(crate::parse_csv(input)?)
+    }
+
+    fn part1(&self, positions: &Self::Input) -> Result<Self::Answer1, Error> {
+        let med_pos = get_median(positions);
+        Ok(sum_diffs_to_target(positions, raw_diff, med_pos))
+    }
+
+    fn part2(&self, positions: &Self::Input) -> Result<Self::Answer2, Error> {
+        let mean_pos = get_mean(positions);
+
+        // True average turns out to be adjacent to the best position.
+        // Trying to represent this less hackily than not rounding to
+        // calculate that average.
+
+        let mut dev = 2;
+        let mut least = usize::MAX;
+        loop {
+            let mut candidates: Vec<usize> = Vec::with_capacity(dev * 2 + 1);
+            for p in mean_pos.saturating_sub(dev)..=mean_pos + dev {
+                let c = sum_diffs_to_target(positions, asc_diff, p);
+                candidates.push(c);
+                if c < least {
+                    least = c;
+                }
+            }
+
+            // Confirm least as low point of parabola. Position 0 is as
+            // far left as the parabola goes, so reaching it counts.
+            let len = candidates.len();
+            if (mean_pos <= dev || least < candidates[0]) && least < candidates[len - 1] {
+                break;
+            }
+
+            dev += 1;
+        }
+
+        Ok(least)
+    }
+}
+
+fn get_median(numbers: &[usize]) -> usize {
+    let mut seq = numbers.to_vec();
+    seq.sort_unstable();
+
+    let len = seq.len();
+    let is_even = len % 2 == 0;
+
+    if is_even {
+        (seq[len / 2] + seq[len / 2 - 1]) / 2
+    } else {
+        seq[len / 2]
+    }
+}
+
+fn get_mean(numbers: &[usize]) -> usize {
+    let mut sum = 0;
+    for number in numbers {
+        sum += number;
+    }
+
+    let mean: f32 = sum as f32 / numbers.len() as f32;
+    mean.round() as usize
+}
+
+fn sum_diffs_to_target(
+    numbers: &[usize],
+    get_diff: fn(a: usize, b: usize) -> usize,
+    target: usize,
+) -> usize {
+    let mut sum = 0;
+    for number in numbers {
+        sum += get_diff(*number, target);
+    }
+
+    sum
+}
+
+fn raw_diff(a: usize, b: usize) -> usize {
+    a.abs_diff(b)
+}
+
+fn asc_diff(a: usize, b: usize) -> usize {
+    let (mut from, mut to) = (a, b);
+    if a > b {
+        from = b;
+        to = a;
+    }
+
+    let mut diff = 0;
+    let mut add = 1;
+
+    while from < to {
+        from += 1;
+        diff += add;
+        add += 1;
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_median_works() {
+        let numbers = &[16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        assert_eq!(get_median(numbers), 2);
+    }
+
+    #[test]
+    fn get_mean_works() {
+        let numbers = &[16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        assert_eq!(get_mean(numbers), 5);
+    }
+
+    #[test]
+    fn sum_diffs_to_target_works() {
+        let numbers = &[16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        assert_eq!(sum_diffs_to_target(numbers, raw_diff, 2), 37);
+        assert_eq!(sum_diffs_to_target(numbers, asc_diff, 5), 168);
+    }
+}
@@ -0,0 +1,46 @@
+pub mod signals;
+
+use crate::{Error, Expect, Input, Solution};
+use signals::*;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<SignalGroup>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn expect(&self) -> Expect {
+        Expect::new().non_empty().charset("abcdefg |")
+    }
+
+    fn parse(&self, input: &Input) -> Result<Self::Input, Error> {
+        Ok(crate::parse_lines(input)?)
+    }
+
+    fn part1(&self, groups: &Self::Input) -> Result<Self::Answer1, Error> {
+        let mut tally = 0;
+        for group in groups {
+            let digits = group.output_as_digits();
+            for d in digits {
+                match d {
+                    ONE | FOUR | SEVEN | EIGHT => tally += 1,
+                    _ => (),
+                }
+            }
+        }
+
+        Ok(tally)
+    }
+
+    fn part2(&self, groups: &Self::Input) -> Result<Self::Answer2, Error> {
+        let mut sum = 0;
+        for group in groups {
+            sum += group
+                .output_as_number()
+                .map_err(|e| Error::invalid(e.to_string()))?;
+        }
+
+        Ok(sum)
+    }
+}
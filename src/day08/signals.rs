@@ -1,3 +1,4 @@
+use crate::Error;
 use std::collections::HashMap;
 use std::num::ParseIntError;
 use std::str::FromStr;
@@ -29,7 +30,7 @@ pub struct SignalGroup {
 }
 
 impl FromStr for SignalGroup {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut digits: Vec<String> = Vec::with_capacity(10);
@@ -50,17 +51,17 @@ impl FromStr for SignalGroup {
         }
 
         if digits.len() != 10 {
-            return Err(format!(
+            return Err(Error::invalid(format!(
                 "expected 10 space-delimited digits before '|', got {}",
                 digits.len()
-            ));
+            )));
         }
 
         if output.len() != 4 {
-            return Err(format!(
+            return Err(Error::invalid(format!(
                 "expected 4 space-delimited digits after '|', got {}",
                 output.len()
-            ));
+            )));
         }
 
         SignalGroup::new(digits, output)
@@ -70,14 +71,14 @@ impl FromStr for SignalGroup {
 impl SignalGroup {
     // This function only accessible indirectly via the FromStr
     // trait implementation, for simplicity.
-    fn new(mut digits: Vec<String>, output: Vec<String>) -> Result<SignalGroup, String> {
+    fn new(mut digits: Vec<String>, output: Vec<String>) -> Result<SignalGroup, Error> {
         let mut zero_to_nine = HashMap::new();
 
         for unique in [ONE, FOUR, SEVEN, EIGHT] {
             let digit = digits
                 .iter()
                 .find(|d| d.len() == unique.len())
-                .ok_or("unique length digit not found")?;
+                .ok_or_else(|| Error::invalid("unique length digit not found"))?;
 
             zero_to_nine.insert(unique, digit.clone());
         }
@@ -88,12 +89,12 @@ impl SignalGroup {
                 let seven = zero_to_nine.get(SEVEN).unwrap();
                 d.len() == THREE.len() && contains_chars_of(d, seven)
             })
-            .ok_or("three not found")?;
+            .ok_or_else(|| Error::invalid("three not found"))?;
 
         let nine = digits
             .iter()
             .find(|d| d.len() == NINE.len() && contains_chars_of(d, three))
-            .ok_or("nine not found")?;
+            .ok_or_else(|| Error::invalid("nine not found"))?;
 
         zero_to_nine.insert(THREE, three.clone());
         zero_to_nine.insert(NINE, nine.clone());
@@ -107,7 +108,7 @@ impl SignalGroup {
                 let seven = zero_to_nine.get(SEVEN).unwrap();
                 d.len() == ZERO.len() && contains_chars_of(d, seven)
             })
-            .ok_or("zero not found")?;
+            .ok_or_else(|| Error::invalid("zero not found"))?;
 
         let five = digits
             .iter()
@@ -115,17 +116,17 @@ impl SignalGroup {
                 let nine = zero_to_nine.get(NINE).unwrap();
                 d.len() == FIVE.len() && contains_chars_of(nine, d)
             })
-            .ok_or("five not found")?;
+            .ok_or_else(|| Error::invalid("five not found"))?;
 
         let two = digits
             .iter()
             .find(|d| d.len() == TWO.len() && *d != five)
-            .ok_or("two not found")?;
+            .ok_or_else(|| Error::invalid("two not found"))?;
 
         let six = digits
             .iter()
             .find(|d| d.len() == SIX.len() && contains_chars_of(d, five))
-            .ok_or("six not found")?;
+            .ok_or_else(|| Error::invalid("six not found"))?;
 
         zero_to_nine.insert(ZERO, zero.clone());
         zero_to_nine.insert(TWO, two.clone());
@@ -0,0 +1,140 @@
+use crate::ParseError;
+use std::fmt::Display;
+use std::io;
+use std::path::Path;
+
+// Everything that can go wrong between reading a day's input and
+// answering it.
+#[derive(Debug)]
+pub enum Error {
+    // The input couldn't be read at all.
+    Io(io::Error),
+    // The input couldn't be parsed, at a known spot.
+    Parse(ParseError),
+    // The input doesn't look like what the day expects, for every reason
+    // found checking it before parsing.
+    Malformed(Vec<ParseError>),
+    // The input parsed, but doesn't make sense as puzzle data, e.g. a
+    // bingo board with the wrong count of numbers.
+    Invalid(String),
+    // The input is fine, but has no answer, e.g. no bingo board ever wins.
+    Unsolvable(String),
+    // The config file couldn't be parsed, at a known spot.
+    Config(ParseError),
+    // The puzzle site couldn't be reached, or refused a request.
+    Remote(String),
+    // There's no solution written for this yet.
+    NotImplemented,
+}
+
+// How many of a malformed input's problems are spelled out; a file with
+// CRLF endings would otherwise list every line.
+const MAX_SHOWN: usize = 10;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn invalid<M: Into<String>>(message: M) -> Error {
+        Error::Invalid(message.into())
+    }
+
+    pub fn unsolvable<M: Into<String>>(message: M) -> Error {
+        Error::Unsolvable(message.into())
+    }
+
+    pub fn remote<M: Into<String>>(message: M) -> Error {
+        Error::Remote(message.into())
+    }
+
+    // What went wrong, without the kind of error it is, for wrapping in
+    // another error that says so itself.
+    pub fn message(&self) -> String {
+        match self {
+            Error::Invalid(m) | Error::Unsolvable(m) | Error::Remote(m) => m.clone(),
+            e => e.to_string(),
+        }
+    }
+
+    // Points parse errors without a path at path. Other errors are
+    // returned as is.
+    pub fn with_path(self, path: &Path) -> Error {
+        match self {
+            Error::Parse(e) => Error::Parse(e.with_path(path)),
+            Error::Config(e) => Error::Config(e.with_path(path)),
+            Error::Malformed(problems) => {
+                Error::Malformed(problems.into_iter().map(|e| e.with_path(path)).collect())
+            }
+            e => e,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "failed to read input: {}", e),
+            Error::Parse(e) => write!(f, "failed to parse input: {}", e),
+            Error::Malformed(problems) => {
+                write!(f, "malformed input, {} problem(s):", problems.len())?;
+                for e in problems.iter().take(MAX_SHOWN) {
+                    write!(f, "\n    {}", e)?;
+                }
+                if problems.len() > MAX_SHOWN {
+                    write!(f, "\n    ... and {} more", problems.len() - MAX_SHOWN)?;
+                }
+                Ok(())
+            }
+            Error::Invalid(m) => write!(f, "invalid puzzle data: {}", m),
+            Error::Unsolvable(m) => write!(f, "unsolvable: {}", m),
+            Error::Config(e) => write!(f, "invalid config: {}", e),
+            Error::Remote(m) => write!(f, "puzzle site: {}", m),
+            Error::NotImplemented => f.write_str("not implemented"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Parse(e) | Error::Config(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Error {
+        Error::Parse(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_path_only_touches_parse_errors() {
+        let err = Error::from(ParseError::new(3, 2, "bad")).with_path(Path::new("data/1.txt"));
+        assert_eq!(
+            err.to_string(),
+            "failed to parse input: data/1.txt:3:2: bad"
+        );
+
+        let err = Error::Malformed(vec![ParseError::new(1, 5, "trailing whitespace")])
+            .with_path(Path::new("data/1.txt"));
+        assert_eq!(
+            err.to_string(),
+            "malformed input, 1 problem(s):\n    data/1.txt:1:5: trailing whitespace"
+        );
+
+        let err = Error::invalid("bad").with_path(Path::new("data/1.txt"));
+        assert_eq!(err.to_string(), "invalid puzzle data: bad");
+    }
+}
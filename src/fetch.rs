@@ -0,0 +1,234 @@
+use crate::http::{client_for, HttpClient, Response};
+use crate::{state_dir, Config, Error, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Where the time of the last request to the site is kept, so the rate
+// limit holds across separate runs.
+pub fn stamp_path() -> PathBuf {
+    state_dir().join("last-request")
+}
+
+// Keeps requests to the puzzle site at least interval apart, even across
+// processes, by recording when the last one was made in a file.
+pub struct RateLimit {
+    stamp: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimit {
+    pub fn new<P: Into<PathBuf>>(stamp: P, interval: Duration) -> RateLimit {
+        RateLimit {
+            stamp: stamp.into(),
+            interval,
+        }
+    }
+
+    // Sleeps until another request is allowed, then records that one is
+    // being made now.
+    pub fn wait(&self) -> Result<()> {
+        if let Some(dir) = self.stamp.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .map(Duration::from_nanos);
+
+        if let Some(last) = last {
+            let since = now().saturating_sub(last);
+            if since < self.interval {
+                thread::sleep(self.interval - since);
+            }
+        }
+
+        fs::write(&self.stamp, now().as_nanos().to_string())?;
+        Ok(())
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    // The input was already in the data directory.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+// Talks to the puzzle site on behalf of a data directory: downloads
+// inputs into it, once each, and posts answers. Every request waits out
+// the rate limit recorded in stamp.
+pub struct Fetcher {
+    config: Config,
+    dir: PathBuf,
+    stamp: PathBuf,
+    client: Box<dyn HttpClient>,
+}
+
+impl Fetcher {
+    pub fn new<P: Into<PathBuf>>(config: Config, dir: P) -> Fetcher {
+        let client = client_for(&config.base_url);
+        Fetcher::with_client(config, dir, client)
+    }
+
+    pub fn with_client<P: Into<PathBuf>>(
+        config: Config,
+        dir: P,
+        client: Box<dyn HttpClient>,
+    ) -> Fetcher {
+        Fetcher {
+            config,
+            dir: dir.into(),
+            stamp: stamp_path(),
+            client,
+        }
+    }
+
+    // Keeps the rate limit's record somewhere other than stamp_path.
+    pub fn with_stamp<P: Into<PathBuf>>(mut self, stamp: P) -> Fetcher {
+        self.stamp = stamp.into();
+        self
+    }
+
+    // Waits out the rate limit shared by every request to the site.
+    fn throttle(&self) -> Result<()> {
+        RateLimit::new(&self.stamp, self.config.min_interval).wait()
+    }
+
+    // The headers every request to the site carries: who's asking, and
+    // as which user.
+    fn headers(&self) -> Result<Vec<(&str, String)>> {
+        Ok(vec![
+            ("Cookie", format!("session={}", self.config.session()?)),
+            ("User-Agent", self.config.user_agent.clone()),
+        ])
+    }
+
+    // Makes sure day's input is in the data directory as {day}.txt,
+    // downloading it unless it's there already. An empty file, like the
+    // placeholder `aoc new` leaves, doesn't count as there.
+    pub fn fetch(&self, day: u8) -> Result<Fetched> {
+        let path = self.dir.join(format!("{}.txt", day));
+        if is_cached(&path) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let headers = self.headers()?;
+        let headers: Vec<(&str, &str)> = headers.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        let url = format!("{}/day/{}/input", self.config.base_url, day);
+
+        self.throttle()?;
+        let response = self.client.get(&url, &headers)?;
+        if response.status != 200 {
+            return Err(Error::remote(format!(
+                "GET {} answered {}: {}",
+                url,
+                response.status,
+                response.body.trim()
+            )));
+        }
+
+        fs::create_dir_all(&self.dir)?;
+        fs::write(&path, response.body)?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    // Posts form to path under the site's base url, e.g. "/day/1/answer".
+    pub(crate) fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<Response> {
+        let headers = self.headers()?;
+        let headers: Vec<(&str, &str)> = headers.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        let url = format!("{}{}", self.config.base_url, path);
+
+        self.throttle()?;
+        let response = self.client.post(&url, &headers, form)?;
+        if response.status != 200 {
+            return Err(Error::remote(format!(
+                "POST {} answered {}: {}",
+                url,
+                response.status,
+                response.body.trim()
+            )));
+        }
+
+        Ok(response)
+    }
+}
+
+fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).map(|m| m.len() > 0).unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::serve;
+    use std::time::Instant;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn config(base_url: String, interval: Duration) -> Config {
+        Config {
+            session: Some("s3cret".to_string()),
+            base_url,
+            min_interval: interval,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn downloads_once_then_uses_cache() {
+        let dir = temp_dir("cache");
+        let (url, requests) = serve(vec![(200, "3,4,3,1,2\n".to_string())]);
+        let fetcher =
+            Fetcher::new(config(url, Duration::from_secs(0)), &dir).with_stamp(dir.join("stamp"));
+
+        let path = dir.join("6.txt");
+        assert_eq!(fetcher.fetch(6).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3,4,3,1,2\n");
+        assert_eq!(fetcher.fetch(6).unwrap(), Fetched::Cached(path));
+
+        let requests = requests.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /day/6/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=s3cret\r\n"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn failed_download_leaves_nothing_behind() {
+        let dir = temp_dir("fail");
+        let (url, requests) = serve(vec![(400, "Please log in.".to_string())]);
+        let fetcher =
+            Fetcher::new(config(url, Duration::from_secs(0)), &dir).with_stamp(dir.join("stamp"));
+
+        let err = fetcher.fetch(9).unwrap_err();
+        assert!(err.to_string().ends_with("answered 400: Please log in."));
+        assert!(!dir.join("9.txt").exists());
+        requests.join().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn requests_wait_out_the_rate_limit() {
+        let dir = temp_dir("limit");
+        let limit = RateLimit::new(dir.join("stamp"), Duration::from_millis(200));
+
+        let start = Instant::now();
+        limit.wait().unwrap();
+        limit.wait().unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
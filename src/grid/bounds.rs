@@ -0,0 +1,99 @@
+use std::fmt::Display;
+
+// The smallest rectangle holding a set of points anywhere in the plane,
+// edges included. Maps those points onto a grid's (x, y) cells, which
+// always start at (0, 0), by offsetting them from the corner (min_x,
+// min_y).
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Bounds {
+    pub min_x: isize,
+    pub min_y: isize,
+    pub max_x: isize,
+    pub max_y: isize,
+}
+
+impl Bounds {
+    // Bounds holding just (x, y).
+    pub fn at(x: isize, y: isize) -> Bounds {
+        Bounds {
+            min_x: x,
+            min_y: y,
+            max_x: x,
+            max_y: y,
+        }
+    }
+
+    // The bounds of points, or None if there aren't any.
+    pub fn around<I: IntoIterator<Item = (isize, isize)>>(points: I) -> Option<Bounds> {
+        let mut points = points.into_iter();
+        let (x, y) = points.next()?;
+        let mut bounds = Bounds::at(x, y);
+        for (x, y) in points {
+            bounds.include(x, y);
+        }
+        Some(bounds)
+    }
+
+    // Grows the bounds, if need be, to hold (x, y).
+    pub fn include(&mut self, x: isize, y: isize) {
+        self.min_x = self.min_x.min(x);
+        self.min_y = self.min_y.min(y);
+        self.max_x = self.max_x.max(x);
+        self.max_y = self.max_y.max(y);
+    }
+
+    pub fn width(&self) -> usize {
+        self.max_x.abs_diff(self.min_x) + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.max_y.abs_diff(self.min_y) + 1
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    // The grid cell for (x, y), or None if it's out of bounds.
+    pub fn offset(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        if !self.contains(x, y) {
+            return None;
+        }
+
+        Some((x.abs_diff(self.min_x), y.abs_diff(self.min_y)))
+    }
+
+    // The point a grid cell stands for; offset undone.
+    pub fn point(&self, x: usize, y: usize) -> (isize, isize) {
+        (self.min_x + x as isize, self.min_y + y as isize)
+    }
+}
+
+impl Display for Bounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}, {}) to ({}, {})",
+            self.min_x, self.min_y, self.max_x, self.max_y
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_points_from_the_corner() {
+        let bounds = Bounds::around(vec![(3, -2), (-4, 5), (0, 0)]).unwrap();
+        assert_eq!(bounds.to_string(), "(-4, -2) to (3, 5)");
+        assert_eq!((bounds.width(), bounds.height()), (8, 8));
+
+        assert_eq!(bounds.offset(-4, -2), Some((0, 0)));
+        assert_eq!(bounds.offset(3, 5), Some((7, 7)));
+        assert_eq!(bounds.offset(4, 0), None);
+        assert_eq!(bounds.point(4, 2), (0, 0));
+
+        assert_eq!(Bounds::around(vec![]), None);
+    }
+}
@@ -0,0 +1,369 @@
+mod bounds;
+mod sparse;
+
+pub use bounds::Bounds;
+pub use sparse::SparseGrid;
+
+use crate::{digit_grid, Error, Input, ParseError};
+use std::fmt::{Display, Write};
+
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// What every grid backend offers: a width by height rectangle of cells
+// addressed by (x, y). Lets code like day 5's line plotting run on a
+// dense Grid or a SparseGrid, whichever suits the data.
+pub trait Cells<T> {
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    // None when (x, y) is out of bounds.
+    fn get(&self, x: usize, y: usize) -> Option<&T>;
+
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T>;
+
+    // The cells holding anything, with their positions, in no particular
+    // order. For a dense grid that's every cell.
+    fn occupied(&self) -> Box<dyn Iterator<Item = ((usize, usize), &T)> + '_>;
+}
+
+// A rectangle of cells addressed by (x, y), x across and y down the rows
+// it was built from. Cells are stored flat, row after row.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    // A width by height grid with every cell set to fill.
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    // Swaps rows for columns, so (x, y) moves to (y, x).
+    pub fn transpose(&self) -> Grid<T> {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in 0..self.width {
+            cells.extend(self.column(x).cloned());
+        }
+
+        Grid {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    // Turns the grid a quarter turn clockwise, as it's printed by rows.
+    pub fn rotate_right(&self) -> Grid<T> {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in 0..self.width {
+            cells.extend(self.column(x).rev().cloned());
+        }
+
+        Grid {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    // Turns the grid a quarter turn anticlockwise.
+    pub fn rotate_left(&self) -> Grid<T> {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in (0..self.width).rev() {
+            cells.extend(self.column(x).cloned());
+        }
+
+        Grid {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    // Builds a grid from rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, Error> {
+        let width = rows.first().map_or(0, |r| r.len());
+        let height = rows.len();
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(Error::invalid(format!(
+                    "row {} is {} wide, expected {}",
+                    y,
+                    row.len(),
+                    width
+                )));
+            }
+
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    // Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // chunks panics on 0, and a zero width grid has no rows to give.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        self.rows().nth(y)
+    }
+
+    // The cells of column x, top to bottom. Empty if x is out of bounds.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    // Positions of the up to 4 cells sharing an edge with (x, y).
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &OFFSETS_4)
+    }
+
+    // Positions of the up to 8 cells sharing an edge or corner with (x, y).
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &OFFSETS_8)
+    }
+
+    fn neighbours(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let nx = x.checked_add_signed(*dx)?;
+            let ny = y.checked_add_signed(*dy)?;
+            self.index(nx, ny).map(|_| (nx, ny))
+        })
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Cells<T> for Grid<T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&T> {
+        Grid::get(self, x, y)
+    }
+
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        Grid::get_mut(self, x, y)
+    }
+
+    fn occupied(&self) -> Box<dyn Iterator<Item = ((usize, usize), &T)> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl Grid<char> {
+    // Parses input as a grid of characters, one row per line.
+    pub fn parse_chars(input: &Input) -> Result<Grid<char>, ParseError> {
+        let mut rows: Vec<Vec<char>> = vec![];
+        for (n, line) in input.lines() {
+            let row: Vec<char> = line.chars().collect();
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::new(
+                        n,
+                        1,
+                        format!(
+                            "expected {} characters like line 1, got {}",
+                            first.len(),
+                            row.len()
+                        ),
+                    ));
+                }
+            }
+
+            rows.push(row);
+        }
+
+        Ok(Grid::from_rows(rows).expect("rows are checked to be uniform"))
+    }
+}
+
+impl Grid<u8> {
+    // Parses input as a grid of single digits, one row per line.
+    pub fn parse_digits(input: &Input) -> Result<Grid<u8>, ParseError> {
+        let rows = digit_grid(input)?;
+        Ok(Grid::from_rows(rows).expect("digit_grid checks rows are uniform"))
+    }
+}
+
+// Prints the rows last to first, so y grows upward like a plot, each
+// cell left-aligned in a column 3 wide.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = String::new();
+        for row in self.rows().rev() {
+            for cell in row {
+                write!(s, "{:<3}", cell).unwrap();
+            }
+
+            writeln!(s).unwrap();
+        }
+
+        f.write_str(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1 2 3
+    // 4 5 6
+    fn small() -> Grid<u8> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn get_is_bounds_checked() {
+        let mut grid = small();
+        assert_eq!(grid.get(2, 1), Some(&6));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+
+        *grid.get_mut(0, 1).unwrap() = 9;
+        assert_eq!(grid.row(1), Some(&[9, 5, 6][..]));
+        assert!(grid.get_mut(5, 5).is_none());
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = small();
+        let corner: Vec<_> = grid.neighbours4(0, 0).collect();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+
+        let middle: Vec<_> = grid.neighbours8(1, 0).collect();
+        assert_eq!(middle, vec![(0, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = small();
+        let rows: Vec<&[u8]> = grid.rows().collect();
+        assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+
+        let columns: Vec<Vec<u8>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = small();
+        let rows = |g: Grid<u8>| g.rows().map(|r| r.to_vec()).collect::<Vec<_>>();
+
+        assert_eq!(
+            rows(grid.transpose()),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        assert_eq!(
+            rows(grid.rotate_right()),
+            vec![vec![4, 1], vec![5, 2], vec![6, 3]]
+        );
+        assert_eq!(
+            rows(grid.rotate_left()),
+            vec![vec![3, 6], vec![2, 5], vec![1, 4]]
+        );
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+
+    #[test]
+    fn parses_chars_and_digits() {
+        let input = Input::new("test.txt", "#.#\n..#\n".to_string());
+        let grid = Grid::parse_chars(&input).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(&'#'));
+
+        let input = Input::new("test.txt", "219\n398\n".to_string());
+        assert_eq!(Grid::parse_digits(&input).unwrap().get(1, 1), Some(&9));
+
+        let input = Input::new("test.txt", "#.#\n.#\n".to_string());
+        assert_eq!(Grid::parse_chars(&input).unwrap_err().line, 2);
+        assert!(Grid::from_rows(vec![vec![1], vec![]]).is_err());
+    }
+
+    #[test]
+    fn displays_bottom_row_first() {
+        assert_eq!(small().to_string(), "4  5  6  \n1  2  3  \n");
+    }
+}
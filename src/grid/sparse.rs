@@ -0,0 +1,86 @@
+use super::Cells;
+use std::collections::HashMap;
+
+// A grid that only stores the cells written to, every other cell reading
+// as the default. Suits huge coordinate spaces with little in them, where
+// a dense grid would allocate every cell up front.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(usize, usize), T>,
+    width: usize,
+    height: usize,
+    default: T,
+}
+
+impl<T: Clone> SparseGrid<T> {
+    pub fn new(width: usize, height: usize, default: T) -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            width,
+            height,
+            default,
+        }
+    }
+
+    // How many cells have been written to.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+}
+
+impl<T: Clone> Cells<T> for SparseGrid<T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        Some(self.cells.get(&(x, y)).unwrap_or(&self.default))
+    }
+
+    // Writing to a cell means storing it, so this stores the default for
+    // any cell not stored yet.
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let default = &self.default;
+        Some(self.cells.entry((x, y)).or_insert_with(|| default.clone()))
+    }
+
+    fn occupied(&self) -> Box<dyn Iterator<Item = ((usize, usize), &T)> + '_> {
+        Box::new(self.cells.iter().map(|(p, cell)| (*p, cell)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_default_until_written() {
+        let mut grid = SparseGrid::new(5_000_000, 5_000_000, 0);
+        assert_eq!(grid.get(4_999_999, 12), Some(&0));
+        assert_eq!(grid.get(5_000_000, 12), None);
+        assert!(grid.is_empty());
+
+        *grid.get_mut(4_999_999, 12).unwrap() += 2;
+        assert_eq!(grid.get(4_999_999, 12), Some(&2));
+        assert!(grid.get_mut(0, 5_000_000).is_none());
+
+        let occupied: Vec<_> = grid.occupied().collect();
+        assert_eq!(occupied, vec![((4_999_999, 12), &2)]);
+    }
+}
@@ -0,0 +1,305 @@
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+
+// What came back from a request. Only what the puzzle site's responses
+// need: the status and the body as text.
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+// Makes requests to the puzzle site. Kept behind a trait so tests can
+// point fetching and submitting at a server of their own.
+pub trait HttpClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response>;
+
+    // Posts form as application/x-www-form-urlencoded fields.
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> io::Result<Response>;
+}
+
+// Picks a client able to reach url: curl for https, since std has no
+// TLS, or a plain socket for http, e.g. a test server on localhost.
+pub fn client_for(url: &str) -> Box<dyn HttpClient> {
+    if url.starts_with("http://") {
+        Box::new(PlainClient)
+    } else {
+        Box::new(CurlClient)
+    }
+}
+
+// Shells out to curl. Headers are handed over on stdin rather than the
+// command line so the session token doesn't show up in the process list.
+pub struct CurlClient;
+
+impl HttpClient for CurlClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+        curl(url, headers, &[])
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> io::Result<Response> {
+        let fields: Vec<String> = form.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+        let mut args = vec![];
+        for field in &fields {
+            args.push("--data-urlencode");
+            args.push(field.as_str());
+        }
+
+        curl(url, headers, &args)
+    }
+}
+
+fn curl(url: &str, headers: &[(&str, &str)], args: &[&str]) -> io::Result<Response> {
+    let mut config = String::new();
+    for (name, value) in headers {
+        config.push_str(&format!(
+            "header = \"{}\"\n",
+            config_escape(&format!("{}: {}", name, value))
+        ));
+    }
+
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--location", "--config", "-"])
+        .args(args)
+        .args(["--write-out", "\n%{http_code}", url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| io::Error::new(e.kind(), format!("failed to run curl: {}", e)))?;
+
+    child
+        .stdin
+        .take()
+        .expect("curl stdin is piped")
+        .write_all(config.as_bytes())?;
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let why = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::other(format!("curl: {}", why.trim())));
+    }
+
+    // The status code is written after the body, on a line of its own.
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout
+        .rsplit_once('\n')
+        .ok_or_else(|| io::Error::other("curl wrote no status code"))?;
+    let status = status
+        .trim()
+        .parse()
+        .map_err(|_| io::Error::other(format!("curl wrote a bad status code '{}'", status)))?;
+
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+// Escapes s for a double quoted string in a curl config file, so that
+// a quote, backslash or line break in a value can't end the string early
+// and slip in options of its own.
+fn config_escape(s: &str) -> String {
+    let mut escaped = String::new();
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Speaks just enough HTTP/1.1 over a socket to talk to a plain http
+// server, one connection per request.
+pub struct PlainClient;
+
+impl HttpClient for PlainClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+        plain("GET", url, headers, None)
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> io::Result<Response> {
+        let body: Vec<String> = form
+            .iter()
+            .map(|(k, v)| format!("{}={}", url_encode(k), url_encode(v)))
+            .collect();
+        plain("POST", url, headers, Some(&body.join("&")))
+    }
+}
+
+fn plain(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> io::Result<Response> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| io::Error::other(format!("not an http url: {}", url)))?;
+    let (host, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+        method, path, host
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    if let Some(body) = body {
+        request.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(body.unwrap_or(""));
+
+    let mut stream = TcpStream::connect(host)?;
+    stream.write_all(request.as_bytes())?;
+    let mut raw = String::new();
+    stream.read_to_string(&mut raw)?;
+
+    parse_response(&raw)
+}
+
+// Percent-encodes everything but unreserved characters.
+fn url_encode(s: &str) -> String {
+    let mut encoded = String::new();
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(b as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    encoded
+}
+
+fn parse_response(raw: &str) -> io::Result<Response> {
+    let bad = || io::Error::new(io::ErrorKind::InvalidData, "malformed http response");
+    let (head, body) = raw.split_once("\r\n\r\n").ok_or_else(bad)?;
+    let status = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(bad)?;
+
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+// Starts a stand-in for the puzzle site on a free local port, answering
+// one connection per response given, in order. Returns its base url and
+// a handle that yields the raw requests it got once it's done.
+#[cfg(test)]
+pub(crate) fn serve(
+    responses: Vec<(u16, String)>,
+) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let mut requests = vec![];
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            requests.push(read_request(&mut stream));
+            let response = format!(
+                "HTTP/1.1 {} Whatever\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+        requests
+    });
+
+    (url, handle)
+}
+
+// Reads one request off stream: its head, plus a body if it says it has
+// one. The client keeps its end open until answered, so this can't just
+// read to the end.
+#[cfg(test)]
+fn read_request(stream: &mut TcpStream) -> String {
+    let mut raw = vec![];
+    let mut buf = [0; 1024];
+    loop {
+        let n = stream.read(&mut buf).unwrap();
+        raw.extend_from_slice(&buf[..n]);
+        let text = String::from_utf8_lossy(&raw).to_string();
+        if let Some(end) = text.find("\r\n\r\n") {
+            let length = text[..end]
+                .lines()
+                .find_map(|l| l.strip_prefix("Content-Length: "))
+                .and_then(|l| l.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            if n == 0 || raw.len() >= end + 4 + length {
+                return text;
+            }
+        } else if n == 0 {
+            return text;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_status_and_body() {
+        let raw = "HTTP/1.1 404 Not Found\r\nContent-Length: 4\r\n\r\nnope";
+        assert_eq!(
+            parse_response(raw).unwrap(),
+            Response {
+                status: 404,
+                body: "nope".to_string()
+            }
+        );
+
+        assert!(parse_response("HTTP/1.1 200 OK\r\n").is_err());
+    }
+
+    #[test]
+    fn escapes_curl_config_strings() {
+        assert_eq!(config_escape("Cookie: session=abc"), "Cookie: session=abc");
+        assert_eq!(
+            config_escape("a\"\nurl = \"http://evil\\"),
+            "a\\\"\\nurl = \\\"http://evil\\\\"
+        );
+    }
+
+    #[test]
+    fn encodes_form_values() {
+        assert_eq!(url_encode("a-1_b.c~"), "a-1_b.c~");
+        assert_eq!(url_encode("1 2&3=4"), "1%202%263%3D4");
+    }
+}
@@ -0,0 +1,247 @@
+// Renders grids and line drawings to image formats simple enough to write
+// by hand: binary PGM and PPM for grids, one pixel per cell, and SVG for
+// lines. Any image viewer worth having opens all three.
+
+use crate::grid::{Bounds, Cells};
+use std::fmt::{Display, Write};
+
+// A cell value that can be shaded by how big it is.
+pub trait Level {
+    fn level(&self) -> f64;
+}
+
+macro_rules! level {
+    ($($t:ty),*) => {
+        $(impl Level for $t {
+            fn level(&self) -> f64 {
+                *self as f64
+            }
+        })*
+    };
+}
+
+level!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+impl Level for bool {
+    fn level(&self) -> f64 {
+        if *self {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    // As SVG and CSS write it, e.g. #ff8000.
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+// Colours for values from lowest to highest: evenly spaced stops, blended
+// in between.
+#[derive(Debug, Clone)]
+pub struct Ramp {
+    stops: Vec<Rgb>,
+}
+
+impl Ramp {
+    // Needs at least one stop; a single stop colours everything the same.
+    pub fn new(stops: Vec<Rgb>) -> Ramp {
+        assert!(!stops.is_empty(), "a colour ramp needs a stop");
+        Ramp { stops }
+    }
+
+    pub fn grey() -> Ramp {
+        Ramp::new(vec![Rgb(0, 0, 0), Rgb(255, 255, 255)])
+    }
+
+    // Black through red and yellow to white, like something heating up.
+    pub fn heat() -> Ramp {
+        Ramp::new(vec![
+            Rgb(0, 0, 0),
+            Rgb(160, 0, 0),
+            Rgb(255, 160, 0),
+            Rgb(255, 255, 255),
+        ])
+    }
+
+    // The colour for t, from 0 at the first stop to 1 at the last.
+    pub fn colour(&self, t: f64) -> Rgb {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let last = self.stops.len() - 1;
+        if last == 0 {
+            return self.stops[0];
+        }
+
+        let at = t * last as f64;
+        let i = (at.floor() as usize).min(last - 1);
+        let (from, to, f) = (self.stops[i], self.stops[i + 1], at - i as f64);
+        let blend = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * f).round() as u8;
+        Rgb(
+            blend(from.0, to.0),
+            blend(from.1, to.1),
+            blend(from.2, to.2),
+        )
+    }
+}
+
+// A greyscale image of grid, black for its lowest value and white for its
+// highest, row 0 at the top.
+pub fn pgm<T: Level, G: Cells<T>>(grid: &G) -> Vec<u8> {
+    let mut image = header("P5", grid);
+    image.extend(levels(grid).into_iter().map(|t| (t * 255.0).round() as u8));
+    image
+}
+
+// A colour image of grid, shaded along ramp from its lowest value to its
+// highest, row 0 at the top.
+pub fn ppm<T: Level, G: Cells<T>>(grid: &G, ramp: &Ramp) -> Vec<u8> {
+    let mut image = header("P6", grid);
+    for t in levels(grid) {
+        let Rgb(r, g, b) = ramp.colour(t);
+        image.extend([r, g, b].iter());
+    }
+    image
+}
+
+fn header<T, G: Cells<T>>(magic: &str, grid: &G) -> Vec<u8> {
+    format!("{}\n{} {}\n255\n", magic, grid.width(), grid.height()).into_bytes()
+}
+
+// Every cell's value, row after row, scaled to between 0 and 1. A grid
+// of one value throughout comes out all 0.
+fn levels<T: Level, G: Cells<T>>(grid: &G) -> Vec<f64> {
+    let mut values = Vec::with_capacity(grid.width() * grid.height());
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            values.push(grid.get(x, y).map_or(0.0, Level::level));
+        }
+    }
+
+    let low = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let high = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let range = high - low;
+    values
+        .into_iter()
+        .map(|v| if range > 0.0 { (v - low) / range } else { 0.0 })
+        .collect()
+}
+
+// An SVG drawing of line segments over a dark background, in the same
+// coordinates as bounds, y down. Displays as the SVG document.
+pub struct Svg {
+    bounds: Bounds,
+    body: String,
+}
+
+// The longer side of a drawing, in pixels, however big its bounds.
+const SVG_SIZE: f64 = 800.0;
+
+impl Svg {
+    pub fn new(bounds: Bounds) -> Svg {
+        Svg {
+            bounds,
+            body: String::new(),
+        }
+    }
+
+    // Draws a line from one point to another. Round ends mean a line from
+    // a point to itself still shows up, as a dot.
+    pub fn line(&mut self, from: (isize, isize), to: (isize, isize), colour: Rgb) {
+        writeln!(
+            self.body,
+            "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\"/>",
+            from.0,
+            from.1,
+            to.0,
+            to.1,
+            colour.hex()
+        )
+        .unwrap();
+    }
+}
+
+impl Display for Svg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let b = self.bounds;
+        let (w, h) = (b.width() as f64, b.height() as f64);
+        let scale = SVG_SIZE / w.max(h);
+
+        // Each point is the centre of a cell, so the view reaches half a
+        // cell past the bounds on every side.
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" width=\"{:.0}\" height=\"{:.0}\">",
+            b.min_x as f64 - 0.5,
+            b.min_y as f64 - 0.5,
+            w,
+            h,
+            w * scale,
+            h * scale
+        )?;
+        writeln!(
+            f,
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#101018\"/>",
+            b.min_x as f64 - 0.5,
+            b.min_y as f64 - 0.5,
+            w,
+            h
+        )?;
+        writeln!(
+            f,
+            "<g stroke-width=\"{}\" stroke-linecap=\"round\" stroke-opacity=\"0.7\">",
+            (w.max(h) / 400.0).max(0.3)
+        )?;
+        write!(f, "{}", self.body)?;
+        writeln!(f, "</g>\n</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn writes_grids_as_pgm_and_ppm() {
+        let grid = Grid::from_rows(vec![vec![0usize, 2], vec![4, 4]]).unwrap();
+        assert_eq!(pgm(&grid), b"P5\n2 2\n255\n\x00\x80\xff\xff".to_vec());
+
+        let image = ppm(&grid, &Ramp::grey());
+        assert!(image.starts_with(b"P6\n2 2\n255\n"));
+        assert_eq!(
+            &image[11..],
+            &[0, 0, 0, 128, 128, 128, 255, 255, 255, 255, 255, 255]
+        );
+
+        let flat = Grid::new(3, 1, true);
+        assert_eq!(&pgm(&flat)[11..], &[0, 0, 0]);
+    }
+
+    #[test]
+    fn blends_between_stops() {
+        let ramp = Ramp::heat();
+        assert_eq!(ramp.colour(0.0), Rgb(0, 0, 0));
+        assert_eq!(ramp.colour(1.0), Rgb(255, 255, 255));
+        assert_eq!(ramp.colour(1.0 / 3.0), Rgb(160, 0, 0));
+        assert_eq!(ramp.colour(0.5), Rgb(208, 80, 0));
+        assert_eq!(ramp.colour(7.0), Rgb(255, 255, 255));
+        assert_eq!(Ramp::new(vec![Rgb(1, 2, 3)]).colour(0.5), Rgb(1, 2, 3));
+    }
+
+    #[test]
+    fn draws_lines_as_svg() {
+        let mut svg = Svg::new(Bounds::around(vec![(-2, 0), (5, 3)]).unwrap());
+        svg.line((-2, 0), (5, 3), Rgb(255, 128, 0));
+        let text = svg.to_string();
+
+        assert!(text.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-2.5 -0.5 8 4\" width=\"800\" height=\"400\">"));
+        assert!(text.contains("<line x1=\"-2\" y1=\"0\" x2=\"5\" y2=\"3\" stroke=\"#ff8000\"/>"));
+        assert!(text.trim_end().ends_with("</svg>"));
+    }
+}
@@ -0,0 +1,332 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+
+// Overrides where puzzle inputs are read from, e.g. to keep them outside
+// the repository.
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+// The directory holding each day's {day}.txt input. Defaults to data/ in
+// the crate root, so binaries work no matter where they're run from.
+pub fn data_dir() -> PathBuf {
+    match env::var_os(DATA_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("data"),
+    }
+}
+
+// Where the runner keeps what it writes for itself: the latest timings,
+// when the site was last asked for something and every answer sent to
+// it. It's under target/, as anything written to the data directory
+// makes the next build embed the inputs again.
+pub fn state_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("aoc")
+}
+
+// Example inputs for day: data/{day}.example.txt, or several like
+// data/{day}.example2.txt. Their expected answers are recorded in the
+// answers file under the example's name.
+pub fn examples(day: u8) -> Vec<Source> {
+    let prefix = format!("{}.example", day);
+    let mut names: Vec<String> = match fs::read_dir(data_dir()) {
+        Ok(entries) => entries
+            .flatten()
+            .filter_map(|e| {
+                let file = e.file_name().to_string_lossy().to_string();
+                match file.strip_suffix(".txt") {
+                    Some(stem) if stem.starts_with(&prefix) => {
+                        Some(stem[stem.find('.').unwrap() + 1..].to_string())
+                    }
+                    _ => None,
+                }
+            })
+            .collect(),
+        Err(_) => vec![],
+    };
+
+    names.sort();
+    names.into_iter().map(Source::Alt).collect()
+}
+
+// Where a day's input comes from.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Source {
+    // {day}.txt in the data directory.
+    Data,
+    // {day}.{name}.txt in the data directory, e.g. an example input.
+    Alt(String),
+    // Whatever is piped in on standard input.
+    Stdin,
+    // An arbitrary file, e.g. an alternate input given on the command line.
+    Path(PathBuf),
+    // The copy of data/{day}.txt compiled into the binary.
+    Embedded,
+}
+
+impl Source {
+    // Reads a command line argument as a source: "-" for stdin,
+    // "embedded" for the compiled-in input, or else a path.
+    pub fn from_arg(arg: &str) -> Source {
+        match arg {
+            "-" => Source::Stdin,
+            "embedded" => Source::Embedded,
+            path => Source::Path(PathBuf::from(path)),
+        }
+    }
+
+    // The name answers for this source are recorded under. The main input
+    // is "input" whether it's read from disk or embedded.
+    pub fn name(&self) -> String {
+        match self {
+            Source::Data | Source::Embedded => "input".to_string(),
+            Source::Alt(name) => name.clone(),
+            Source::Path(path) => path.display().to_string(),
+            Source::Stdin => "-".to_string(),
+        }
+    }
+
+    // The reverse of name: "input" is the day's main input, anything that
+    // looks like a path is a path, and any other name is an alternate input
+    // in the data directory.
+    pub fn from_name(name: &str) -> Source {
+        match name {
+            "input" => Source::Data,
+            "-" => Source::Stdin,
+            n if n.contains('/') || n.contains('.') => Source::Path(PathBuf::from(n)),
+            n => Source::Alt(n.to_string()),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> io::Result<Input> {
+        match self {
+            Source::Data => {
+                let path = data_dir().join(format!("{}.txt", day));
+                read_file(&path)
+            }
+            Source::Alt(name) => {
+                let path = data_dir().join(format!("{}.{}.txt", day, name));
+                read_file(&path)
+            }
+            Source::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Ok(Input::new("<stdin>", text))
+            }
+            Source::Path(path) => read_file(path),
+            Source::Embedded => match EMBEDDED.iter().find(|(d, _)| *d == day) {
+                Some((_, text)) => Ok(Input::new(
+                    format!("data/{}.txt (embedded)", day),
+                    text.to_string(),
+                )),
+                None => Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no input embedded for day {}", day),
+                )),
+            },
+        }
+    }
+}
+
+// Reads path, naming it in any error since io errors don't say which
+// file they were about.
+fn read_file(path: &Path) -> io::Result<Input> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(Input::new(path, text)),
+        Err(why) => Err(io::Error::new(
+            why.kind(),
+            format!("{}: {}", path.display(), why),
+        )),
+    }
+}
+
+// A day's puzzle input, kept alongside the path it came from so that
+// parse errors can point back at the file. The input is read eagerly,
+// the whole file into memory up front; only walking it by lines() is
+// lazy. Inputs are a few kilobytes, lines borrow straight from the text
+// and validation looks at all of it anyway, so streaming it in wouldn't
+// buy anything.
+pub struct Input {
+    path: PathBuf,
+    text: String,
+}
+
+impl Input {
+    pub fn new<P: Into<PathBuf>>(path: P, text: String) -> Input {
+        Input {
+            path: path.into(),
+            text,
+        }
+    }
+
+    // Reads {day}.txt from the data directory.
+    pub fn read(day: u8) -> io::Result<Input> {
+        Source::Data.read(day)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    // Lazily walks the already read text a line at a time, numbering
+    // lines from 1. Nothing is copied; each line borrows from the text. Blank
+    // lines at the very end of the input are skipped.
+    pub fn lines(&self) -> Lines<'_> {
+        let text = self.text.trim_end_matches(&['\n', '\r'][..]);
+        Lines {
+            inner: text.lines().enumerate(),
+        }
+    }
+}
+
+pub struct Lines<'a> {
+    inner: std::iter::Enumerate<std::str::Lines<'a>>,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(i, line)| (i + 1, line))
+    }
+}
+
+// Where in the input parsing failed and why. Lines and columns count
+// from 1. The path is usually filled in after the fact by whoever read
+// the input, since the code doing the parsing rarely knows it.
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub path: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new<M: Into<String>>(line: usize, column: usize, message: M) -> ParseError {
+        ParseError {
+            path: None,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    // Builds an error pointing at token, which must be a slice of line,
+    // e.g. one of the pieces returned by line.split(','). If it isn't,
+    // the error points at the start of the line instead.
+    pub fn at<M: Into<String>>(line_no: usize, line: &str, token: &str, message: M) -> ParseError {
+        let start = line.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        let inside = offset <= line.len() && offset + token.len() <= line.len();
+        let column = if inside { offset + 1 } else { 1 };
+
+        ParseError::new(line_no, column, message)
+    }
+
+    pub fn with_path(mut self, path: &Path) -> ParseError {
+        if self.path.is_none() {
+            self.path = Some(path.to_path_buf());
+        }
+
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path.display())?;
+        }
+
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_are_numbered_from_one() {
+        let input = Input::new("test.txt", "a\nb\r\n\nc\n\n".to_string());
+        let lines: Vec<(usize, &str)> = input.lines().collect();
+        assert_eq!(lines, vec![(1, "a"), (2, "b"), (3, ""), (4, "c")]);
+    }
+
+    #[test]
+    fn at_finds_column_of_token() {
+        let line = "0,9 -> 5,x";
+        let token = line.split(',').nth(2).unwrap();
+        let err = ParseError::at(3, line, token, "not a number");
+        assert_eq!(err.line, 3);
+        assert_eq!(err.column, 10);
+        assert_eq!(err.to_string(), "3:10: not a number");
+    }
+
+    #[test]
+    fn at_falls_back_to_line_start() {
+        let err = ParseError::at(1, "abc", "elsewhere", "oops");
+        assert_eq!(err.column, 1);
+    }
+
+    #[test]
+    fn from_arg_works() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(Source::from_arg("embedded"), Source::Embedded);
+        assert_eq!(
+            Source::from_arg("alt/5.txt"),
+            Source::Path(PathBuf::from("alt/5.txt"))
+        );
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for source in [
+            Source::Data,
+            Source::Stdin,
+            Source::Alt("example".to_string()),
+            Source::Path(PathBuf::from("alt/5.txt")),
+        ]
+        .iter()
+        {
+            assert_eq!(&Source::from_name(&source.name()), source);
+        }
+
+        assert_eq!(Source::Embedded.name(), "input");
+    }
+
+    #[test]
+    fn examples_works() {
+        assert_eq!(examples(1), vec![Source::Alt("example".to_string())]);
+        assert!(examples(25).is_empty());
+    }
+
+    #[test]
+    fn embedded_matches_data() {
+        for (day, text) in EMBEDDED {
+            let input = Source::Embedded.read(*day).unwrap();
+            assert_eq!(input.text(), *text);
+        }
+
+        assert!(Source::Embedded.read(0).is_err());
+    }
+
+    #[test]
+    fn with_path_works() {
+        let err = ParseError::new(2, 4, "bad").with_path(Path::new("data/2.txt"));
+        assert_eq!(err.to_string(), "data/2.txt:2:4: bad");
+    }
+}
@@ -1,19 +1,164 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+// Parity is checked with % 2 == 0, which builds on toolchains older than
+// usize::is_multiple_of.
+#![allow(unknown_lints, clippy::manual_is_multiple_of)]
 
-pub fn read_input(day: u8) -> std::io::Result<Vec<String>> {
-    let fmt = format!("data/{}.txt", day);
-    let path = Path::new(&fmt);
+mod answers;
+pub mod bench;
+mod config;
+mod error;
+pub mod fetch;
+pub mod grid;
+pub mod http;
+pub mod image;
+mod input;
+mod parse;
+pub mod registry;
+pub mod submit;
+mod validate;
 
-    let input = File::open(&path)?;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+// New days are added above here by `aoc new`.
 
-    let mut lines: Vec<String> = Vec::new();
+pub use answers::{answers_path, check_examples, Answers, Key};
+pub use config::{config_path, Config, CONFIG_VAR, SESSION_VAR};
+pub use error::{Error, Result};
+pub use input::{data_dir, examples, state_dir, Input, Lines, ParseError, Source, DATA_DIR_VAR};
+pub use parse::{blocks, digit_grid, parse_csv, parse_csv_line, parse_lines, parse_lines_with};
+pub use validate::Expect;
 
-    for l in io::BufReader::new(input).lines() {
-        let line = l?;
-        lines.push(line);
+use std::fmt::Display;
+
+// A day's puzzle, split into the steps every day has in common: turn the
+// raw input into some model of the puzzle, then answer each part
+// from that model. Keeping parse separate from the parts means the parts
+// can be called on their own, e.g. from tests with a hand-built input.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    // What the raw input should look like. It's checked before parse is
+    // called, which can then assume it.
+    fn expect(&self) -> Expect {
+        Expect::new()
+    }
+
+    fn parse(&self, input: &Input) -> Result<Self::Input>;
+
+    // Checks input against expect, then parses it, with any error
+    // pointing into input's file. How input should always be parsed
+    // outside of tests.
+    fn checked_parse(&self, input: &Input) -> Result<Self::Input> {
+        let problems = self.expect().check(input);
+        if !problems.is_empty() {
+            return Err(Error::Malformed(problems).with_path(input.path()));
+        }
+
+        self.parse(input).map_err(|e| e.with_path(input.path()))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2>;
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+// Object-safe face of a Solution. Each day has its own input and answer
+// types, so this is what lets the runner keep them all in one list.
+pub trait Puzzle {
+    fn prepare<'a>(&'a self, input: &Input) -> Result<Box<dyn Parsed + 'a>>;
+}
+
+// A day's input, already parsed and ready to answer either part.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Result<String>;
+}
+
+struct Prepared<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<'a, S: Solution> Parsed for Prepared<'a, S> {
+    fn solve(&self, part: Part) -> Result<String> {
+        match part {
+            Part::One => Ok(self.solution.part1(&self.input)?.to_string()),
+            Part::Two => Ok(self.solution.part2(&self.input)?.to_string()),
+        }
+    }
+}
+
+impl<S: Solution> Puzzle for S {
+    fn prepare<'a>(&'a self, input: &Input) -> Result<Box<dyn Parsed + 'a>> {
+        let input = self.checked_parse(input)?;
+        Ok(Box::new(Prepared {
+            solution: self,
+            input,
+        }))
+    }
+}
+
+// Reads the input for day, then parses it and prints both answers with
+// solution. Shared by every day's binary so they all behave the same.
+// Failures are reported on stderr, exiting non-zero.
+//
+// The input defaults to the data directory, but an optional argument can
+// point at another file, "-" for stdin or "embedded", as in:
+//
+//     cargo day 5 -- alt/5.txt
+pub fn run<S: Solution>(day: u8, solution: &S) {
+    let source = match std::env::args().nth(1) {
+        Some(arg) => Source::from_arg(&arg),
+        None => Source::Data,
+    };
+
+    let parsed = match source.read(day).map_err(Error::from) {
+        Ok(input) => solution.prepare(&input),
+        Err(why) => Err(why),
+    };
+
+    let parsed = match parsed {
+        Ok(p) => p,
+        Err(why) => {
+            eprintln!("day {}: {}", day, why);
+            std::process::exit(1);
+        }
+    };
+
+    let mut failed = false;
+    for part in [Part::One, Part::Two].iter() {
+        match parsed.solve(*part) {
+            Ok(answer) => println!("part {}: {}", part, answer),
+            Err(Error::NotImplemented) => println!("part {}: not implemented", part),
+            Err(why) => {
+                eprintln!("part {}: {}", part, why);
+                failed = true;
+            }
+        }
     }
 
-    Ok(lines)
+    if failed {
+        std::process::exit(1);
+    }
 }
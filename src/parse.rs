@@ -0,0 +1,212 @@
+use crate::{Input, ParseError};
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Parses every line of input as a T, e.g. parse_lines::<usize>(input).
+pub fn parse_lines<T>(input: &Input) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    parse_lines_with(input, |line| line.parse::<T>())
+}
+
+// Like parse_lines, but with a custom parser for types that don't parse
+// the FromStr way, e.g. binary numbers via u16::from_str_radix.
+pub fn parse_lines_with<T, E, F>(input: &Input, parse: F) -> Result<Vec<T>, ParseError>
+where
+    E: Display,
+    F: Fn(&str) -> Result<T, E>,
+{
+    let mut values = vec![];
+    for (n, line) in input.lines() {
+        match parse(line) {
+            Ok(v) => values.push(v),
+            Err(why) => {
+                return Err(ParseError::new(
+                    n,
+                    1,
+                    format!("couldn't parse '{}': {}", line, why),
+                ))
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+// Parses input made of a single line of comma-separated values.
+pub fn parse_csv<T>(input: &Input) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut lines = input.lines();
+    let (n, line) = match lines.next() {
+        Some(l) => l,
+        None => {
+            return Err(ParseError::new(
+                1,
+                1,
+                "expected a line of comma-separated values",
+            ))
+        }
+    };
+
+    if let Some((extra, _)) = lines.next() {
+        return Err(ParseError::new(
+            extra,
+            1,
+            "expected only one line of comma-separated values",
+        ));
+    }
+
+    parse_csv_line(n, line)
+}
+
+// Parses one line of comma-separated values, pointing any error at the
+// offending field.
+pub fn parse_csv_line<T>(line_no: usize, line: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut values = vec![];
+    for field in line.split(',') {
+        match field.parse::<T>() {
+            Ok(v) => values.push(v),
+            Err(why) => {
+                return Err(ParseError::at(
+                    line_no,
+                    line,
+                    field,
+                    format!("couldn't parse '{}': {}", field, why),
+                ))
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+// Splits input into groups of lines separated by one or more blank lines.
+// The last group is emitted whether or not the input ends with a blank.
+pub fn blocks(input: &Input) -> Vec<Vec<(usize, &str)>> {
+    let mut blocks = vec![];
+    let mut block = vec![];
+
+    for (n, line) in input.lines() {
+        if line.trim().is_empty() {
+            if !block.is_empty() {
+                blocks.push(block);
+                block = vec![];
+            }
+
+            continue;
+        }
+
+        block.push((n, line));
+    }
+
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+
+    blocks
+}
+
+// Parses input made of rows of single digits, like "2199943210", into
+// rows of numbers. Every row must be the same width.
+pub fn digit_grid(input: &Input) -> Result<Vec<Vec<u8>>, ParseError> {
+    let mut rows: Vec<Vec<u8>> = vec![];
+
+    for (n, line) in input.lines() {
+        let mut row = Vec::with_capacity(line.len());
+        for (i, c) in line.chars().enumerate() {
+            match c.to_digit(10) {
+                Some(d) => row.push(d as u8),
+                None => return Err(ParseError::new(n, i + 1, format!("'{}' is not a digit", c))),
+            }
+        }
+
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::new(
+                    n,
+                    1,
+                    format!(
+                        "expected {} digits like line 1, got {}",
+                        first.len(),
+                        row.len()
+                    ),
+                ));
+            }
+        }
+
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(text: &str) -> Input {
+        Input::new("test.txt", text.to_string())
+    }
+
+    #[test]
+    fn parse_lines_works() {
+        assert_eq!(
+            parse_lines::<u32>(&input("1\n20\n300\n")),
+            Ok(vec![1, 20, 300])
+        );
+
+        let err = parse_lines::<u32>(&input("1\nx\n")).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn parse_csv_works() {
+        assert_eq!(
+            parse_csv::<u8>(&input("3,4,3,1,2\n\n")),
+            Ok(vec![3, 4, 3, 1, 2])
+        );
+
+        let err = parse_csv::<u8>(&input("3,4,x")).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+
+        assert!(parse_csv::<u8>(&input("")).is_err());
+        assert!(parse_csv::<u8>(&input("1,2\n3,4")).is_err());
+    }
+
+    #[test]
+    fn blocks_emits_final_block() {
+        let text = input("a\nb\n\n\nc\nd\n\ne");
+        let blocks = blocks(&text);
+        assert_eq!(
+            blocks,
+            vec![
+                vec![(1, "a"), (2, "b")],
+                vec![(5, "c"), (6, "d")],
+                vec![(8, "e")],
+            ]
+        );
+    }
+
+    #[test]
+    fn digit_grid_works() {
+        assert_eq!(
+            digit_grid(&input("123\n456")),
+            Ok(vec![vec![1, 2, 3], vec![4, 5, 6]])
+        );
+
+        let err = digit_grid(&input("123\n4x6")).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+
+        let err = digit_grid(&input("123\n45")).unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+}
@@ -0,0 +1,35 @@
+// Every day of the calendar and its solution, so the runner and tests
+// can drive all of them.
+
+use crate::Puzzle;
+
+pub const DAYS: u8 = 25;
+
+// A day of the calendar, with its solution if it has one.
+pub struct Entry {
+    pub day: u8,
+    pub puzzle: Option<Box<dyn Puzzle>>,
+}
+
+// Lists all 25 days in order. Days without a solution yet have no puzzle.
+pub fn entries() -> Vec<Entry> {
+    let mut entries: Vec<Entry> = (1..=DAYS).map(|day| Entry { day, puzzle: None }).collect();
+
+    let solved: Vec<(u8, Box<dyn Puzzle>)> = vec![
+        (1, Box::new(crate::day01::Day1)),
+        (2, Box::new(crate::day02::Day2)),
+        (3, Box::new(crate::day03::Day3)),
+        (4, Box::new(crate::day04::Day4::default())),
+        (5, Box::new(crate::day05::Day5::default())),
+        (6, Box::new(crate::day06::Day6)),
+        (7, Box::new(crate::day07::Day7)),
+        (8, Box::new(crate::day08::Day8)),
+        // New days are added above here by `aoc new`.
+    ];
+
+    for (day, puzzle) in solved {
+        entries[day as usize - 1].puzzle = Some(puzzle);
+    }
+
+    entries
+}
@@ -0,0 +1,401 @@
+use crate::fetch::Fetcher;
+use crate::{state_dir, Error, ParseError, Part, Result};
+use std::fmt::{Display, Write};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Every answer ever submitted.
+pub fn guesses_path() -> PathBuf {
+    state_dir().join("guesses.tsv")
+}
+
+// What the site made of a submitted answer.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong, without saying which way.
+    Wrong,
+    // Submitted too soon after the last guess; try again after this long.
+    Wait(Duration),
+    // The part was solved already, so the answer wasn't checked.
+    AlreadySolved,
+    // A response none of the above matched.
+    Unrecognized,
+}
+
+impl Verdict {
+    // Reads the verdict out of the page the site answers a submission
+    // with, by the sentences it's known to use.
+    pub fn from_page(page: &str) -> Verdict {
+        if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if page.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::Wait(wait_time(page).unwrap_or_default())
+        } else if page.contains("Did you already complete it?") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unrecognized
+        }
+    }
+
+    // Whether the answer is known to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait(_) => "wait",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::Unrecognized => "unrecognized",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Verdict> {
+        let verdict = match name {
+            "correct" => Verdict::Correct,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            "wait" => Verdict::Wait(Duration::default()),
+            "already-solved" => Verdict::AlreadySolved,
+            "unrecognized" => Verdict::Unrecognized,
+            _ => return None,
+        };
+
+        Some(verdict)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => f.write_str("correct"),
+            Verdict::TooHigh => f.write_str("wrong, too high"),
+            Verdict::TooLow => f.write_str("wrong, too low"),
+            Verdict::Wrong => f.write_str("wrong"),
+            Verdict::Wait(d) => write!(f, "too soon, wait {}s", d.as_secs()),
+            Verdict::AlreadySolved => f.write_str("already solved"),
+            Verdict::Unrecognized => f.write_str("unrecognized response"),
+        }
+    }
+}
+
+// Finds the "You have 4m 32s left to wait." in a too-soon page.
+fn wait_time(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for amount in page[start..end].split_whitespace() {
+        let unit = amount.chars().last()?;
+        let n: u64 = amount[..amount.len() - 1].parse().ok()?;
+        secs += match unit {
+            'h' => n * 3600,
+            'm' => n * 60,
+            's' => n,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+// One answer submitted for a day's part and what the site made of it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Guess {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    // Seconds since the epoch.
+    pub time: u64,
+}
+
+// Every guess made so far, in the order made. Stored as tab-separated
+// day, part, answer, verdict and time, one per line.
+#[derive(Debug, Default)]
+pub struct Guesses(Vec<Guess>);
+
+impl Guesses {
+    // Loads guesses from path. A missing file is just no guesses yet.
+    pub fn load(path: &Path) -> Result<Guesses> {
+        let text = match fs::read_to_string(path) {
+            Ok(t) => t,
+            Err(why) if why.kind() == io::ErrorKind::NotFound => return Ok(Guesses::default()),
+            Err(why) => return Err(why.into()),
+        };
+
+        Guesses::parse(&text).map_err(|e| e.with_path(path))
+    }
+
+    fn parse(text: &str) -> Result<Guesses> {
+        let mut guesses = vec![];
+        for (i, line) in text.lines().enumerate() {
+            let n = i + 1;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() != 5 {
+                return Err(
+                    ParseError::new(n, 1, "expected day, part, answer, verdict and time").into(),
+                );
+            }
+
+            let day = fields[0]
+                .parse::<u8>()
+                .map_err(|_| ParseError::at(n, line, fields[0], "invalid day"))?;
+            let part = match fields[1] {
+                "1" => Part::One,
+                "2" => Part::Two,
+                p => return Err(ParseError::at(n, line, p, "expected part 1 or 2").into()),
+            };
+            let verdict = Verdict::from_name(fields[3])
+                .ok_or_else(|| ParseError::at(n, line, fields[3], "unknown verdict"))?;
+            let time = fields[4]
+                .parse::<u64>()
+                .map_err(|_| ParseError::at(n, line, fields[4], "invalid time"))?;
+
+            guesses.push(Guess {
+                day,
+                part,
+                answer: fields[2].to_string(),
+                verdict,
+                time,
+            });
+        }
+
+        Ok(Guesses(guesses))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut text = String::from("# day\tpart\tanswer\tverdict\ttime\n");
+        for g in &self.0 {
+            writeln!(
+                text,
+                "{}\t{}\t{}\t{}\t{}",
+                g.day,
+                g.part,
+                g.answer,
+                g.verdict.name(),
+                g.time
+            )
+            .unwrap();
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, text)
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, verdict: Verdict) {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        self.0.push(Guess {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            time,
+        });
+    }
+
+    pub fn of(&self, day: u8, part: Part) -> impl Iterator<Item = &Guess> {
+        self.0
+            .iter()
+            .filter(move |g| g.day == day && g.part == part)
+    }
+
+    // Why answer shouldn't be submitted for day's part, if the guesses so
+    // far say it can't be right: the part is solved already, the same
+    // answer was wrong before, or it's a number on the wrong side of one
+    // that was too high or too low.
+    pub fn refuse(&self, day: u8, part: Part, answer: &str) -> Option<String> {
+        let number = answer.parse::<i128>().ok();
+        for g in self.of(day, part) {
+            if g.verdict == Verdict::Correct {
+                return Some(format!("already solved with {}", g.answer));
+            }
+
+            if g.verdict.is_wrong() && g.answer == answer {
+                return Some(format!("{} was already guessed: {}", answer, g.verdict));
+            }
+
+            let bound = match (number, g.answer.parse::<i128>()) {
+                (Some(n), Ok(b)) => Some((n, b)),
+                _ => None,
+            };
+            match (&g.verdict, bound) {
+                (Verdict::TooHigh, Some((n, b))) if n >= b => {
+                    return Some(format!("{} was already too high", b))
+                }
+                (Verdict::TooLow, Some((n, b))) if n <= b => {
+                    return Some(format!("{} was already too low", b))
+                }
+                _ => (),
+            }
+        }
+
+        None
+    }
+}
+
+// What became of an attempt to submit an answer.
+#[derive(Debug, PartialEq)]
+pub enum Submission {
+    // Not sent, since the guesses so far already rule it out.
+    Refused(String),
+    Judged(Verdict),
+}
+
+// Submits answer for day's part, unless guesses rule it out, and records
+// the verdict in guesses.
+pub fn submit(
+    fetcher: &Fetcher,
+    guesses: &mut Guesses,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Submission> {
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Err(Error::invalid(format!("'{}' isn't an answer", answer)));
+    }
+
+    if let Some(why) = guesses.refuse(day, part, answer) {
+        return Ok(Submission::Refused(why));
+    }
+
+    let level = part.to_string();
+    let page = fetcher.post(
+        &format!("/day/{}/answer", day),
+        &[("level", &level), ("answer", answer)],
+    )?;
+
+    let verdict = Verdict::from_page(&page.body);
+    guesses.record(day, part, answer, verdict.clone());
+    Ok(Submission::Judged(verdict))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::serve;
+    use crate::Config;
+
+    fn page(sentence: &str) -> String {
+        format!(
+            "<html><main><article><p>{}</p></article></main></html>",
+            sentence
+        )
+    }
+
+    #[test]
+    fn reads_verdicts() {
+        let cases = vec![
+            ("That's the right answer!  You are one gold star closer.", Verdict::Correct),
+            ("That's not the right answer; your answer is too high.", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low.", Verdict::TooLow),
+            ("That's not the right answer.  If you're stuck...", Verdict::Wrong),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait.",
+                Verdict::Wait(Duration::from_secs(272)),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::AlreadySolved,
+            ),
+            ("Something else entirely.", Verdict::Unrecognized),
+        ];
+
+        for (sentence, verdict) in cases {
+            assert_eq!(Verdict::from_page(&page(sentence)), verdict, "{}", sentence);
+        }
+    }
+
+    #[test]
+    fn refuses_answers_ruled_out() {
+        let mut guesses = Guesses::default();
+        guesses.record(1, Part::One, "500", Verdict::TooHigh);
+        guesses.record(1, Part::One, "100", Verdict::TooLow);
+        guesses.record(1, Part::One, "abc", Verdict::Wrong);
+
+        assert!(guesses.refuse(1, Part::One, "500").is_some());
+        assert!(guesses.refuse(1, Part::One, "600").is_some());
+        assert!(guesses.refuse(1, Part::One, "99").is_some());
+        assert!(guesses.refuse(1, Part::One, "abc").is_some());
+        assert_eq!(guesses.refuse(1, Part::One, "300"), None);
+        assert_eq!(guesses.refuse(1, Part::Two, "500"), None);
+
+        guesses.record(1, Part::One, "300", Verdict::Correct);
+        assert_eq!(
+            guesses.refuse(1, Part::One, "301"),
+            Some("already solved with 300".to_string())
+        );
+    }
+
+    #[test]
+    fn guesses_round_trip() {
+        let mut guesses = Guesses::default();
+        guesses.record(7, Part::Two, "168", Verdict::TooLow);
+        guesses.record(7, Part::Two, "170", Verdict::Wait(Duration::from_secs(60)));
+
+        let path = std::env::temp_dir().join(format!("aoc-guesses-{}.tsv", std::process::id()));
+        guesses.save(&path).unwrap();
+        let loaded = Guesses::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let verdicts: Vec<&Verdict> = loaded.of(7, Part::Two).map(|g| &g.verdict).collect();
+        assert_eq!(
+            verdicts,
+            vec![&Verdict::TooLow, &Verdict::Wait(Duration::default())]
+        );
+    }
+
+    #[test]
+    fn submits_to_the_site_once_per_new_answer() {
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let (url, requests) = serve(vec![(
+            200,
+            page("That's not the right answer; your answer is too high."),
+        )]);
+        let config = Config {
+            session: Some("s3cret".to_string()),
+            base_url: url,
+            min_interval: Duration::from_secs(0),
+            ..Config::default()
+        };
+        let fetcher = Fetcher::new(config, &dir).with_stamp(dir.join("stamp"));
+        let mut guesses = Guesses::default();
+
+        let first = submit(&fetcher, &mut guesses, 5, Part::Two, "18674").unwrap();
+        assert_eq!(first, Submission::Judged(Verdict::TooHigh));
+        let again = submit(&fetcher, &mut guesses, 5, Part::Two, "18674").unwrap();
+        assert!(matches!(again, Submission::Refused(_)));
+
+        let requests = requests.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("POST /day/5/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=18674"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{Input, ParseError};
+use std::ops::RangeInclusive;
+
+// What a day expects its input to look like, checked before it's parsed
+// so a bad input gets every problem with it reported at once rather than
+// a panic or the first parse error. Line endings and trailing whitespace
+// are always checked; everything else is opted into, as in:
+//
+//     Expect::new().non_empty().charset("01").uniform_width()
+#[derive(Debug, Clone, Default)]
+pub struct Expect {
+    non_empty: bool,
+    lines: Option<RangeInclusive<usize>>,
+    charset: Option<&'static str>,
+    uniform_width: bool,
+    csv_line: bool,
+}
+
+impl Expect {
+    pub fn new() -> Expect {
+        Expect::default()
+    }
+
+    // The input has at least one non-blank line.
+    pub fn non_empty(mut self) -> Expect {
+        self.non_empty = true;
+        self
+    }
+
+    // The input has a count of lines within range.
+    pub fn lines(mut self, range: RangeInclusive<usize>) -> Expect {
+        self.lines = Some(range);
+        self
+    }
+
+    // Every line is made only of characters in chars. Blank lines are
+    // always allowed.
+    pub fn charset(mut self, chars: &'static str) -> Expect {
+        self.charset = Some(chars);
+        self
+    }
+
+    // Every non-blank line is as wide as the first.
+    pub fn uniform_width(mut self) -> Expect {
+        self.uniform_width = true;
+        self
+    }
+
+    // The input is one non-empty line of comma separated values, none of
+    // them empty.
+    pub fn csv_line(mut self) -> Expect {
+        self.non_empty = true;
+        self.lines = Some(1..=1);
+        self.csv_line = true;
+        self
+    }
+
+    // Checks input against every expectation, returning all the problems
+    // found in the order they appear in the input, by line then column.
+    pub fn check(&self, input: &Input) -> Vec<ParseError> {
+        let mut problems = vec![];
+        let text = input.text().trim_end_matches('\n');
+        let lines: Vec<&str> = if text.is_empty() {
+            vec![]
+        } else {
+            text.split('\n').collect()
+        };
+
+        if self.non_empty && lines.iter().all(|l| l.trim().is_empty()) {
+            problems.push(ParseError::new(1, 1, "input is empty"));
+            return problems;
+        }
+
+        if let Some(range) = &self.lines {
+            if !range.contains(&lines.len()) {
+                let expected = if range.start() == range.end() {
+                    range.start().to_string()
+                } else {
+                    format!("{} to {}", range.start(), range.end())
+                };
+                let noun = if expected == "1" { "line" } else { "lines" };
+                problems.push(ParseError::new(
+                    lines.len().max(1),
+                    1,
+                    format!("expected {} {}, found {}", expected, noun, lines.len()),
+                ));
+            }
+        }
+
+        let mut width = None;
+        for (i, raw) in lines.iter().enumerate() {
+            let line_no = i + 1;
+            let line = raw.strip_suffix('\r').unwrap_or(raw);
+            if line.len() < raw.len() {
+                problems.push(ParseError::new(
+                    line_no,
+                    line.len() + 1,
+                    "line ends with CRLF, expected LF",
+                ));
+            }
+
+            let trimmed = line.trim_end();
+            if trimmed.len() < line.len() {
+                problems.push(ParseError::new(
+                    line_no,
+                    trimmed.len() + 1,
+                    "trailing whitespace",
+                ));
+            }
+
+            if let Some(chars) = self.charset {
+                if let Some((col, c)) = trimmed.char_indices().find(|(_, c)| !chars.contains(*c)) {
+                    problems.push(ParseError::new(
+                        line_no,
+                        col + 1,
+                        format!("unexpected character {:?}", c),
+                    ));
+                }
+            }
+
+            if self.uniform_width && !line.is_empty() {
+                match width {
+                    None => width = Some(line.len()),
+                    Some(w) if w != line.len() => problems.push(ParseError::new(
+                        line_no,
+                        1,
+                        format!("expected a line {} wide, found {}", w, line.len()),
+                    )),
+                    Some(_) => (),
+                }
+            }
+
+            if self.csv_line {
+                for value in line.split(',') {
+                    if value.trim().is_empty() {
+                        problems.push(ParseError::at(line_no, line, value, "empty value"));
+                    }
+                }
+            }
+        }
+
+        // Checks over the whole input, like the line count, are found
+        // before the ones line by line; sort puts them back in place.
+        problems.sort_by_key(|p| (p.line, p.column));
+        problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(expect: Expect, text: &str) -> Vec<String> {
+        let input = Input::new("test.txt", text.to_string());
+        expect.check(&input).iter().map(|e| e.to_string()).collect()
+    }
+
+    #[test]
+    fn clean_input_passes() {
+        let expect = Expect::new().non_empty().charset("01").uniform_width();
+        assert!(check(expect, "0010\n1101\n").is_empty());
+        assert!(check(Expect::new().csv_line(), "3,4,3,1,2\n").is_empty());
+    }
+
+    #[test]
+    fn reports_every_problem() {
+        let expect = Expect::new().charset("01").uniform_width().lines(1..=2);
+        let problems = check(expect, "0010\r\n1121 \n011\n");
+        assert_eq!(
+            problems,
+            vec![
+                "1:5: line ends with CRLF, expected LF",
+                "2:1: expected a line 4 wide, found 5",
+                "2:3: unexpected character '2'",
+                "2:5: trailing whitespace",
+                "3:1: expected 1 to 2 lines, found 3",
+                "3:1: expected a line 4 wide, found 3",
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_input_is_reported_alone() {
+        let problems = check(Expect::new().csv_line(), "\n\n");
+        assert_eq!(problems, vec!["1:1: input is empty"]);
+        assert!(check(Expect::new(), "").is_empty());
+    }
+
+    #[test]
+    fn csv_line_finds_empty_values() {
+        let problems = check(Expect::new().csv_line(), "3,,4\n5\n");
+        assert_eq!(
+            problems,
+            vec!["1:3: empty value", "2:1: expected 1 line, found 2"]
+        );
+    }
+}
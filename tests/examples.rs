@@ -0,0 +1,15 @@
+use advent_21::registry;
+
+// Solves every example fixture of every implemented day, checking each
+// part against the answer recorded for that example.
+#[test]
+fn examples_match_recorded_answers() {
+    let mut failures = vec![];
+    for entry in registry::entries() {
+        if let Some(puzzle) = &entry.puzzle {
+            failures.extend(advent_21::check_examples(entry.day, puzzle.as_ref()));
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}